@@ -0,0 +1,151 @@
+//! Counting of heap allocations per quiz step
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether allocations are counted at all
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The allocation counters of a thread
+struct Counters {
+    /// The number of allocations
+    allocations: Cell<u64>,
+    /// The number of bytes allocated
+    bytes: Cell<u64>,
+    /// The number of bytes allocated minus the number of bytes freed
+    ///
+    /// May become negative if memory allocated on another thread is freed.
+    live: Cell<i64>,
+    /// The highest value of `live` since the counters were last reset
+    peak: Cell<i64>,
+}
+
+thread_local! {
+    /// The allocation counters of the current thread
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+/// Update the counters of the current thread, if counting is enabled
+fn count(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // the counters are gone while the thread is shutting down
+    let _ = COUNTERS.try_with(|counters| {
+        if allocated > 0 {
+            counters.allocations.set(counters.allocations.get() + 1);
+            counters.bytes.set(counters.bytes.get() + allocated as u64);
+        }
+
+        let live = counters.live.get() + allocated as i64 - freed as i64;
+        counters.live.set(live);
+        counters.peak.set(counters.peak.get().max(live));
+    });
+}
+
+/// A global allocator which counts the allocations of each thread once [`enable`]d
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Start counting allocations
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// The allocations of a single step of a quiz
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct AllocStats {
+    /// The number of allocations, including reallocations
+    pub allocations: u64,
+    /// The number of bytes allocated
+    #[serde(rename = "alloc_bytes")]
+    pub bytes: u64,
+    /// The highest number of bytes live at once, on top of those live before the step
+    #[serde(rename = "peak_bytes")]
+    pub peak: u64,
+}
+
+/// Run a closure and count its allocations on the current thread
+///
+/// Returns [`None`] as statistics if counting is not enabled.
+pub fn measured<T, F>(func: F) -> anyhow::Result<(T, Option<AllocStats>)>
+where
+    F: FnOnce() -> anyhow::Result<T>,
+{
+    if !ENABLED.load(Ordering::Relaxed) {
+        return Ok((func()?, None));
+    }
+
+    let before = COUNTERS.with(|counters| {
+        counters.peak.set(counters.live.get());
+        (
+            counters.allocations.get(),
+            counters.bytes.get(),
+            counters.live.get(),
+        )
+    });
+
+    let result = func()?;
+
+    let stats = COUNTERS.with(|counters| AllocStats {
+        allocations: counters.allocations.get() - before.0,
+        bytes: counters.bytes.get() - before.1,
+        peak: (counters.peak.get() - before.2).max(0) as u64,
+    });
+
+    Ok((result, Some(stats)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counting() {
+        enable();
+
+        let (_, stats) = measured(|| {
+            let mut v = vec![0u8; 1000];
+            v.extend_from_slice(&[1; 1000]);
+            drop(v);
+            Ok(())
+        })
+        .unwrap();
+        let stats = stats.unwrap();
+
+        // the allocation and the reallocation
+        assert_eq!(stats.allocations, 2);
+        assert!(stats.bytes >= 3000);
+        assert!((2000..3000).contains(&stats.peak));
+    }
+}
@@ -0,0 +1,138 @@
+//! The typed answers of the quizzes
+//!
+//! ```
+//! use aoc21::util::Grid;
+//! use aoc21::Answer;
+//!
+//! assert!(Answer::from(1548u64).matches(" 1548\n"));
+//!
+//! let letter = Answer::Bitmap(Grid::try_from((vec![true, false, true, true], 2)).unwrap());
+//! assert_eq!(letter.to_string(), "#.\n##");
+//! assert!(letter.matches("#.\n##"));
+//! ```
+use crate::util::Grid;
+use std::fmt;
+
+/// The answer to a part of a quiz
+///
+/// An empty [`Answer::Text`] marks a part which is not solved yet.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A number, which almost all answers are
+    Int(i128),
+    /// A piece of text
+    Text(String),
+    /// A picture of dots which reads as letters, drawn with `#` and `.`
+    Bitmap(Grid<bool>),
+}
+
+/// The kind of an [`Answer`], as written to machine-readable output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// An [`Answer::Int`]
+    Int,
+    /// An [`Answer::Text`]
+    Text,
+    /// An [`Answer::Bitmap`]
+    Bitmap,
+}
+
+impl Kind {
+    /// The name of the kind
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Kind::Int => "int",
+            Kind::Text => "text",
+            Kind::Bitmap => "bitmap",
+        }
+    }
+}
+
+impl Answer {
+    /// The kind of the answer
+    pub fn kind(&self) -> Kind {
+        match self {
+            Answer::Int(_) => Kind::Int,
+            Answer::Text(_) => Kind::Text,
+            Answer::Bitmap(_) => Kind::Bitmap,
+        }
+    }
+
+    /// Whether the part has no answer yet
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Answer::Text(text) if text.is_empty())
+    }
+
+    /// Whether the answer spans multiple lines
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Int(_) => false,
+            Answer::Text(text) => text.contains('\n'),
+            Answer::Bitmap(_) => true,
+        }
+    }
+
+    /// Check the answer against an expected one written as text
+    ///
+    /// Numbers are compared by value and texts without leading and trailing whitespace. Bitmaps are compared
+    /// row by row, ignoring the whitespace around each row.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Int(n) => expected.trim().parse() == Ok(*n),
+            Answer::Text(text) => text.trim() == expected.trim(),
+            Answer::Bitmap(_) => self
+                .to_string()
+                .lines()
+                .eq(expected.trim().lines().map(str::trim)),
+        }
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Answer::Text(String::new())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Bitmap(bitmap) => bitmap
+                .render_with(|&dot| if dot { '#' } else { '.' })
+                .fmt(f),
+        }
+    }
+}
+
+/// Implement [`From`] for [`Answer::Int`] for integer types which fit into an [`i128`]
+macro_rules! from_int {
+    ($($int:ty),+) => {
+        $(impl From<$int> for Answer {
+            fn from(n: $int) -> Self {
+                Answer::Int(n as i128)
+            }
+        })+
+    };
+}
+
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(bitmap: Grid<bool>) -> Self {
+        Answer::Bitmap(bitmap)
+    }
+}
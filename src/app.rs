@@ -1,59 +1,478 @@
 //! Application logic
-use aoc21::Quizzer;
+use crate::alloc::{self, AllocStats};
+use crate::bench::{self, Budget};
+use crate::check::{Answers, Verdict};
+use crate::fetch;
+use crate::history::{Comparison, History, Run, Step};
+use crate::input::{self, Inputs};
+use crate::isolate::{self, isolated};
+use crate::pool;
+use crate::render::{Sink, Target};
+use crate::report::{nanos, Format, ParseResult, PartResult, Reporter};
+use crate::scaffold;
+use crate::submit;
+use anyhow::Context;
+use aoc21::days::Day;
+use aoc21::{Answer, AnyParsed};
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::fs;
 use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::thread;
 use std::time::{Duration, Instant};
 
-/// Compose all [`aoc21::Quizzer`]s and their respective inputs into a [`Vec`]
-#[macro_export]
-macro_rules! all_the_quizzes {
-    ($module:ident -> $input:expr) => {{
-        let quiz = Box::new($module::Quiz);
-        let input = include_str!($input);
+/// How often the input file is polled in watch mode
+const WATCH_INTERVAL: Duration = Duration::from_millis(250);
 
-        (quiz, input)
-    }};
-    ($($module:ident: $input:expr),+) => {{
-        let quizzes: Vec<(Box<dyn ::aoc21::Quizzer>, _)> = vec![
-            $(all_the_quizzes!($module -> $input)),+
-        ];
+/// A unit of work for the thread pool: some parts of a quiz together with its input
+struct Job<'a> {
+    /// The quiz to run
+    entry: &'a Day,
+    /// The input of the quiz, or why it could not be loaded
+    input: &'a anyhow::Result<Arc<Cow<'static, str>>>,
+    /// The parsed input, shared by all jobs of the quiz and set by the first one to need it
+    parsed: &'a OnceLock<ParseResult>,
+    /// The parts selected to run for the quiz, in order
+    selected: &'static [usize],
+    /// The parts to run in this job, in order
+    parts: &'static [usize],
+}
+
+impl Job<'_> {
+    /// Whether the job starts a quiz
+    fn is_first(&self) -> bool {
+        self.parts.first() == self.selected.first()
+    }
+
+    /// Whether the job finishes a quiz
+    fn is_last(&self) -> bool {
+        self.parts.last() == self.selected.last()
+    }
+
+    /// Run the parts of the job, one after another, parsing the input first if necessary
+    ///
+    /// Each step is [`isolated`], so a panic or, if a timeout is given, a step which does not finish in time
+    /// only fails that step.
+    ///
+    /// Returns [`None`] if there is no input to run the parts on.
+    fn run(&self, timeout: Option<Duration>) -> Option<Vec<PartResult>> {
+        let Day { day, quiz, .. } = self.entry;
+        let input = self.input.as_ref().ok()?;
+        let parsed = self.parsed.get_or_init(|| {
+            let (quiz, input) = (*quiz, Arc::clone(input));
+            parsed(*day, || {
+                isolated(timeout, move || {
+                    alloc::measured(|| Ok(Arc::new(quiz.parse(&input)?)))
+                })
+            })
+        });
 
-        quizzes
-    }};
-    ($($module:ident: $input:expr),+,) => {{
-        all_the_quizzes!($($module: $input),+)
-    }};
+        let results = self
+            .parts
+            .iter()
+            .map(|&part| match &parsed.parsed {
+                Ok(parsed) => {
+                    let (quiz, parsed) = (*quiz, Arc::clone(parsed));
+                    timed(*day, part, || {
+                        isolated(timeout, move || {
+                            alloc::measured(|| match part {
+                                1 => quiz.part1(&parsed),
+                                _ => quiz.part2(&parsed),
+                            })
+                        })
+                    })
+                }
+                Err(_) => unparsed(*day, part),
+            })
+            .collect();
+
+        Some(results)
+    }
 }
 
-/// Run the two parts of a quiz, printing their output and execution time
+/// Run the selected parts of the quizzes on their worked examples and verify the answers
 ///
-/// Note that the printed execution time does *NOT* have benchmark-quality.
-fn run_quiz(index: usize, quiz: &dyn ::aoc21::Quizzer, input: &str) -> Duration {
-    let duration = timed(index, 1, || quiz.part1(input)) + timed(index, 2, || quiz.part2(input));
-    println!();
+/// Parts which an example does not cover are not run. Returns whether all answers passed.
+fn run_examples<W: Write>(
+    entries: &[&Day],
+    parts: &[usize],
+    timeout: Option<Duration>,
+    reporter: &mut Reporter<W>,
+) -> anyhow::Result<bool> {
+    let mut total = Duration::default();
+    let mut passed = true;
+
+    let start = Instant::now();
+
+    for Day {
+        day, title, quiz, ..
+    } in entries
+    {
+        let examples = quiz.examples();
+
+        if examples.is_empty() {
+            reporter.skipped(*day, parts, &anyhow::anyhow!("no examples"))?;
+            continue;
+        }
+
+        for (n, example) in examples.iter().enumerate() {
+            let covered: Vec<_> = parts
+                .iter()
+                .filter_map(|&part| Some((part, example.expected(part)?)))
+                .collect();
+
+            if covered.is_empty() {
+                continue;
+            }
+
+            match examples.len() {
+                1 => reporter.quiz(*day, title)?,
+                _ => reporter.quiz(*day, &format!("{}, example {}", title, n + 1))?,
+            }
+
+            let (parse_quiz, input) = (*quiz, example.input);
+            let parsed = parsed(*day, || {
+                isolated(timeout, move || {
+                    alloc::measured(|| Ok(Arc::new(parse_quiz.parse(input)?)))
+                })
+            });
+            total += parsed.elapsed;
+            reporter.parse(&parsed)?;
+
+            for (part, expected) in covered {
+                let result = match &parsed.parsed {
+                    Ok(parsed) => {
+                        let (quiz, parsed) = (*quiz, Arc::clone(parsed));
+                        timed(*day, part, || {
+                            isolated(timeout, move || {
+                                alloc::measured(|| match part {
+                                    1 => quiz.part1(&parsed),
+                                    _ => quiz.part2(&parsed),
+                                })
+                            })
+                        })
+                    }
+                    Err(_) => unparsed(*day, part),
+                };
+
+                let verdict = result.verify_example(expected);
+                passed &= !verdict.is_failure();
+                total += result.elapsed;
+                reporter.part(&result, Some(&verdict))?;
+            }
+
+            reporter.end_quiz()?;
+        }
+    }
+
+    reporter.summary(total, start.elapsed(), passed)?;
+
+    Ok(passed)
+}
+
+/// Run the selected parts of a quiz whenever the content of its input file changes
+///
+/// The file is polled until the process is interrupted, answers which differ from those of the previous run are
+/// highlighted.
+fn watch(
+    entry: &Day,
+    path: &Path,
+    parts: &'static [usize],
+    timeout: Option<Duration>,
+    answers: Option<&Answers>,
+    format: Format,
+) -> anyhow::Result<bool> {
+    let mut reporter = Reporter::new(format, stdout().lock())?;
+    let mut input = None;
+    let mut previous = Vec::new();
+    let mut unreadable = false;
 
-    duration
+    eprintln!(
+        "Watching {} for changes, press Ctrl-C to stop",
+        path.display()
+    );
+
+    loop {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                unreadable = false;
+
+                if input.as_ref() != Some(&content) {
+                    let normalized = input::normalize(Cow::Owned(content.clone()));
+                    input::warn(entry, &normalized);
+
+                    previous = rerun(
+                        entry,
+                        &normalized,
+                        parts,
+                        timeout,
+                        answers,
+                        &previous,
+                        &mut reporter,
+                    )?;
+                    input = Some(content);
+                }
+            }
+            // editors may replace the file while saving, so only complain once
+            Err(e) => {
+                if !unreadable {
+                    eprintln!("reading input {} failed: {}", path.display(), e);
+                }
+                unreadable = true;
+            }
+        }
+
+        thread::sleep(WATCH_INTERVAL);
+    }
 }
 
-/// Execute a closure and print its output and execution time
+/// Run the selected parts of a quiz on an input once, as part of [`watch`]
 ///
-/// Note that the printed execution time does *NOT* have benchmark-quality
-fn timed<F>(quiz: usize, part: usize, func: F) -> Duration
+/// Answers which differ from the `previous` ones are reported as changed. Returns the latest answer of each part,
+/// a part which failed keeps its previous answer.
+fn rerun<W: Write>(
+    entry: &Day,
+    input: &str,
+    parts: &'static [usize],
+    timeout: Option<Duration>,
+    answers: Option<&Answers>,
+    previous: &[(usize, Answer)],
+    reporter: &mut Reporter<W>,
+) -> anyhow::Result<Vec<(usize, Answer)>> {
+    let input = Ok(Arc::new(Cow::Owned(input.to_string())));
+    let parsed = OnceLock::new();
+    let job = Job {
+        entry,
+        input: &input,
+        parsed: &parsed,
+        selected: parts,
+        parts,
+    };
+    let results = job.run(timeout).unwrap_or_default();
+
+    reporter.quiz(entry.day, entry.title)?;
+
+    if let Some(parsed) = parsed.get() {
+        reporter.parse(parsed)?;
+    }
+
+    let mut latest = Vec::new();
+
+    for result in &results {
+        let verdict = answers.map(|answers| result.verify(answers));
+        reporter.part(result, verdict.as_ref())?;
+
+        let before = previous.iter().find(|(part, _)| *part == result.part);
+
+        match (&result.answer, before) {
+            (Ok(answer), Some((_, before))) if answer != before => {
+                reporter.changed(result.day, result.part, before)?;
+                latest.push((result.part, answer.clone()));
+            }
+            (Ok(answer), _) => latest.push((result.part, answer.clone())),
+            (Err(_), Some(before)) => latest.push(before.clone()),
+            (Err(_), None) => {}
+        }
+    }
+
+    reporter.end_quiz()?;
+
+    Ok(latest)
+}
+
+/// The result of a part whose input could not be parsed
+fn unparsed(day: usize, part: usize) -> PartResult {
+    PartResult {
+        day,
+        part,
+        answer: Err(anyhow::anyhow!(
+            "quiz{:0>2} part{} failed: the input could not be parsed",
+            day,
+            part
+        )),
+        elapsed: Duration::default(),
+        alloc: None,
+    }
+}
+
+/// Split the given quizzes into jobs, either running the selected parts of a quiz together or each on its own
+fn jobs<'a>(
+    entries: &[&'a Day],
+    inputs: &'a [anyhow::Result<Arc<Cow<'static, str>>>],
+    parsed: &'a [OnceLock<ParseResult>],
+    selected: &'static [usize],
+    parallel_parts: bool,
+) -> Vec<Job<'a>> {
+    let groups: Vec<&'static [usize]> = if parallel_parts {
+        selected.chunks(1).collect()
+    } else {
+        vec![selected]
+    };
+
+    entries
+        .iter()
+        .zip(inputs)
+        .zip(parsed)
+        .flat_map(|((entry, input), parsed)| {
+            groups.iter().map(move |parts| Job {
+                entry,
+                input,
+                parsed,
+                selected,
+                parts,
+            })
+        })
+        .collect()
+}
+
+/// The parts to run, either both or only the given one
+fn selected_parts(part: Option<usize>) -> anyhow::Result<&'static [usize]> {
+    match part {
+        None => Ok(&[1, 2]),
+        Some(1) => Ok(&[1]),
+        Some(2) => Ok(&[2]),
+        Some(part) => Err(anyhow::anyhow!("there is no part {}, only 1 and 2", part)),
+    }
+}
+
+/// Parse the input of a quiz and measure the execution time
+///
+/// The closure returns the allocations of parsing, if they were counted.
+///
+/// Note that the measured execution time does *NOT* have benchmark-quality, use [`bench`] for that
+fn parsed<F>(day: usize, func: F) -> ParseResult
 where
-    F: Fn() -> String,
+    F: FnOnce() -> anyhow::Result<(Arc<AnyParsed>, Option<AllocStats>)>,
 {
     let start = Instant::now();
-    let result = func();
+    let result = func().with_context(|| format!("quiz{:0>2} parsing failed", day));
     let elapsed = start.elapsed();
 
-    println!(
-        "quiz{:0>2}-part{} {:>9} us {:>16}",
-        quiz,
+    let (parsed, alloc) = split(result);
+
+    ParseResult {
+        day,
+        parsed,
+        elapsed,
+        alloc,
+    }
+}
+
+/// Execute a closure and measure its execution time
+///
+/// The closure returns the allocations of the part, if they were counted.
+///
+/// Note that the measured execution time does *NOT* have benchmark-quality, use [`bench`] for that
+fn timed<F>(day: usize, part: usize, func: F) -> PartResult
+where
+    F: FnOnce() -> anyhow::Result<(Answer, Option<AllocStats>)>,
+{
+    let start = Instant::now();
+    let result = func().with_context(|| format!("quiz{:0>2} part{} failed", day, part));
+    let elapsed = start.elapsed();
+
+    let (answer, alloc) = split(result);
+
+    PartResult {
+        day,
         part,
-        elapsed.as_micros(),
-        result
-    );
+        answer,
+        elapsed,
+        alloc,
+    }
+}
 
-    elapsed
+/// Split the result of a step from its allocation statistics
+fn split<T>(
+    result: anyhow::Result<(T, Option<AllocStats>)>,
+) -> (anyhow::Result<T>, Option<AllocStats>) {
+    match result {
+        Ok((value, alloc)) => (Ok(value), alloc),
+        Err(e) => (Err(e), None),
+    }
+}
+
+/// Benchmark parsing and the selected parts of a quiz and print statistics on their execution times
+fn bench_quiz(entry: &Day, input: &str, parts: &[usize], budget: Budget) {
+    let Day {
+        day, title, quiz, ..
+    } = entry;
+
+    println!("Day {}: {}", day, title);
+
+    if bench_step(*day, "parse", budget, || quiz.parse(input)) {
+        // the parts are benchmarked on the result of a single, unmeasured parse
+        if let Ok(parsed) = quiz.parse(input) {
+            for part in parts {
+                match part {
+                    1 => bench_step(*day, "part1", budget, || quiz.part1(&parsed)),
+                    _ => bench_step(*day, "part2", budget, || quiz.part2(&parsed)),
+                };
+            }
+        }
+    }
+
+    println!();
+}
+
+/// Benchmark a single step of a quiz and print statistics on its execution times
+///
+/// Returns whether the step succeeded.
+fn bench_step<F, T>(day: usize, step: &str, budget: Budget, func: F) -> bool
+where
+    F: Fn() -> anyhow::Result<T>,
+{
+    match bench::measure(budget, func) {
+        Ok(stats) => {
+            println!("quiz{:0>2}-{:<5} {}", day, step, stats);
+            true
+        }
+        Err(e) => {
+            eprintln!("quiz{:0>2} {} failed: {:#}", day, step, e);
+            println!("quiz{:0>2}-{:<5} ERROR", day, step);
+            false
+        }
+    }
+}
+
+/// Run the selected parts of a quiz frame by frame and render the frames to the target
+fn visualize_quiz(
+    entry: &Day,
+    input: &str,
+    parts: &[usize],
+    target: &Target,
+) -> anyhow::Result<()> {
+    let Day { day, quiz, .. } = entry;
+
+    if !quiz.can_visualize() {
+        return Err(anyhow::anyhow!("quiz{:0>2} can not be visualized", day));
+    }
+
+    let parsed = quiz
+        .parse(input)
+        .with_context(|| format!("quiz{:0>2} parsing failed", day))?;
+
+    for &part in parts {
+        let mut sink = Sink::new(target, *day, part)?;
+
+        quiz.visualize(&parsed, part, &mut |frame| sink.frame(&frame))
+            .with_context(|| format!("quiz{:0>2} part{} failed", day, part))?;
+
+        match target {
+            Target::Terminal => println!(),
+            Target::Images(dir) => println!(
+                "quiz{:0>2}-part{} wrote {} frames to {}",
+                day,
+                part,
+                sink.frames(),
+                dir.display()
+            ),
+        }
+    }
+
+    Ok(())
 }
 
 /// Write the help message to the given [`Write`]r
@@ -61,14 +480,97 @@ pub fn help<W: Write>(mut w: W) {
     write!(
         w,
         "
-usage: aoc-2021 [-q QUIZ | --single-quiz QUIZ] [-l | --latest-only]
+usage: aoc-2021 [-q DAY | --single-quiz DAY] [-l | --latest-only]
+                [-i PATH | --input PATH] [-d DIR | --input-dir DIR]
+                [-c | --check] [-a PATH | --answers PATH] [-e | --examples]
+                [-b | --bench [BUDGET]] [-w | --watch] [-v | --visualize [DIR]]
+                [-f FORMAT | --format FORMAT] [-j N | --jobs N] [-p | --parallel-parts]
+                [-P PART | --part PART] [-t SECS | --timeout SECS]
+                [-m | --alloc-stats] [-H PATH | --history PATH] [--no-history]
+                [-C | --compare [BASELINE]] [-T PCT | --threshold PCT]
+       aoc-2021 fetch [-d DIR | --input-dir DIR] [--base-url URL]
+                [--session-file PATH] [--interval SECS] DAY...
+       aoc-2021 submit [-i PATH | --input PATH] [-d DIR | --input-dir DIR]
+                [--log PATH] [--base-url URL] [--session-file PATH]
+                [--interval SECS] DAY PART
+       aoc-2021 new [--title TITLE] [--src DIR] DAY
 
 Run advent of code 2021
 
+Commands:
+    fetch                       Download the inputs of the given days into DIR, unless they are
+                                there already
+    new                         Generate the module of a new quiz and register it
+    submit                      Run a part of a quiz and submit its answer, unless the same answer
+                                was submitted before
+
 Options:
     -h, --help                  Print this help message
-    -q, --single-quiz   QUIZ    Only run the specified quiz
+    -q, --single-quiz   DAY     Only run the quiz of the specified day
     -l, --latest-only           Only run the latest quiz
+    -i, --input         PATH    Read the input of a single quiz from PATH ('-' for stdin)
+    -d, --input-dir     DIR     Read the input of day N from DIR/N or DIR/N.txt [default: inputs]
+    -c, --check                 Verify the results against the expected answers
+    -a, --answers       PATH    The expected answers for --check [default: answers.txt]
+    -e, --examples              Run the quizzes on the worked examples of their descriptions and
+                                verify the answers given there
+    -b, --bench         BUDGET  Benchmark each part for BUDGET runs ('100') or a time ('2s', '500ms')
+                                after warming up for a tenth of the budget [default: 100]
+    -w, --watch                 Run the quiz again whenever the file given by --input changes and
+                                highlight the answers which changed
+    -v, --visualize     DIR     Animate the simulation of a quiz in the terminal or, given DIR,
+                                write its frames to DIR as numbered PGM images
+    -f, --format        FORMAT  Write results as 'text', 'json' (one object per line) or 'csv'
+                                [default: text]
+    -j, --jobs          N       Run up to N quizzes at once on separate threads [default: 1]
+    -p, --parallel-parts        Run the two parts of a quiz as separate jobs
+    -P, --part          PART    Only run part 1 or 2 of the quizzes, the input is parsed nonetheless
+    -t, --timeout       SECS    Give up on parsing or a part of a quiz after SECS seconds
+    -m, --alloc-stats           Report the number of allocations, the bytes allocated and the
+                                peak of live bytes of each step next to its execution time
+    -H, --history       PATH    Append the execution times of each run to PATH
+                                [default: history.jsonl]
+        --no-history            Do not append the execution times to the history
+    -C, --compare       BASELINE
+                                Compare the execution times with the latest run of the git commit
+                                BASELINE or, without it, the previous run in the history
+    -T, --threshold     PCT     Flag steps which slowed down by more than PCT percent [default: 10]
+
+Options of fetch and submit:
+        --base-url      URL     The Advent of Code website [default: $AOC_BASE_URL or
+                                https://adventofcode.com]
+        --session-file  PATH    Read the session token from PATH unless $AOC_SESSION is set
+                                [default: ~/.config/aoc21/session]
+        --interval      SECS    Wait at least SECS seconds between two requests [default: 5]
+        --log           PATH    Log the submitted answers and the outcomes in PATH
+                                [default: submissions.jsonl]
+
+Options of new:
+        --title         TITLE   The title of the quiz [default: Untitled]
+        --src           DIR     The source directory of the crate [default: src]
+
+Results are always reported in order of the days, the summary reports the wall
+time of the run next to the summed execution time of all steps.
+
+A part which panics or times out is reported as PANIC or TIMEOUT and does not
+keep the remaining quizzes from running.
+
+Runs are recorded by the short hash of the checked out git commit or, outside
+of a git repository, by their timestamp. BASELINE may also be a timestamp.
+
+In watch mode the input is polled until the process is interrupted, its runs
+are not recorded in the history.
+
+Only some quizzes can be visualized, e.g. days 9 and 11.
+
+Inputs which are not found fall back to the ones embedded into the binary.
+
+Inputs are normalised before they are parsed: a byte order mark, the carriage
+returns of CRLF line endings and trailing blank lines are removed. Empty
+inputs, tabs and grids with lines of different lengths are warned about.
+
+The answers file contains one 'DAY PART ANSWER' line per day and part,
+newlines within an answer are written as '\\n'.
 "
     )
     .unwrap();
@@ -79,61 +581,497 @@ Options:
 struct Args {
     /// Option to print the help output
     help: bool,
-    /// Option to run only a single quiz
+    /// Option to run only the quiz of a single day
     single_quiz: Option<usize>,
     /// Option to run only the latest available quiz
     latest_only: bool,
+    /// Option to read the input of a single quiz from a file
+    input: Option<PathBuf>,
+    /// Option to read the inputs from a directory
+    input_dir: PathBuf,
+    /// Option to verify the results against the expected answers
+    check: bool,
+    /// Option to read the expected answers from a file
+    answers: PathBuf,
+    /// Option to run the quizzes on their worked examples instead of their inputs
+    examples: bool,
+    /// Option to benchmark the quizzes
+    bench: Option<Budget>,
+    /// Option to run the quiz again whenever its input file changes
+    watch: bool,
+    /// Option to render the simulation of a quiz
+    visualize: Option<Target>,
+    /// Option to select the output format
+    format: Format,
+    /// Option to run the quizzes on multiple threads
+    jobs: usize,
+    /// Option to run the two parts of a quiz on separate threads
+    parallel_parts: bool,
+    /// Option to run only one part of the quizzes
+    part: Option<usize>,
+    /// Option to give up on steps of a quiz which take too long
+    timeout: Option<Duration>,
+    /// Option to count the allocations of each step of a quiz
+    alloc_stats: bool,
+    /// Option to record the execution times in a history file
+    history: PathBuf,
+    /// Option to not record the execution times
+    no_history: bool,
+    /// Option to compare the execution times with the previous or a named run of the history
+    compare: Option<Option<String>>,
+    /// Option to set the slowdown in percent above which a step counts as a regression
+    threshold: f64,
 }
 
 impl Args {
     /// Generates [`Args`] with the help of the pico-args crate
-    fn try_from_pico_args() -> Result<Args, anyhow::Error> {
-        let mut args = pico_args::Arguments::from_env();
+    fn try_from_vec(mut args: Vec<OsString>) -> Result<Args, anyhow::Error> {
+        let bench = take_optional_value(&mut args, ["-b", "--bench"])
+            .map(|budget| budget.map_or_else(|| Ok(Budget::default()), |budget| budget.parse()))
+            .transpose()?;
+        let compare = take_optional_value(&mut args, ["-C", "--compare"]);
+        let visualize = take_optional_value(&mut args, ["-v", "--visualize"]).map(Target::new);
+
+        let mut args = pico_args::Arguments::from_vec(args);
 
         Ok(Self {
             help: args.contains(["-h", "--help"]),
             single_quiz: args.opt_value_from_str(["-q", "--single-quiz"])?,
             latest_only: args.contains(["-l", "--latest-only"]),
+            input: args.opt_value_from_str(["-i", "--input"])?,
+            input_dir: args
+                .opt_value_from_str(["-d", "--input-dir"])?
+                .unwrap_or_else(|| PathBuf::from("inputs")),
+            check: args.contains(["-c", "--check"]),
+            answers: args
+                .opt_value_from_str(["-a", "--answers"])?
+                .unwrap_or_else(|| PathBuf::from("answers.txt")),
+            examples: args.contains(["-e", "--examples"]),
+            bench,
+            watch: args.contains(["-w", "--watch"]),
+            visualize,
+            format: args
+                .opt_value_from_str(["-f", "--format"])?
+                .unwrap_or(Format::Text),
+            jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+            parallel_parts: args.contains(["-p", "--parallel-parts"]),
+            part: args.opt_value_from_str(["-P", "--part"])?,
+            timeout: args.opt_value_from_fn(["-t", "--timeout"], isolate::parse_timeout)?,
+            alloc_stats: args.contains(["-m", "--alloc-stats"]),
+            history: args
+                .opt_value_from_str(["-H", "--history"])?
+                .unwrap_or_else(|| PathBuf::from("history.jsonl")),
+            no_history: args.contains("--no-history"),
+            compare,
+            threshold: args
+                .opt_value_from_fn(["-T", "--threshold"], parse_threshold)?
+                .unwrap_or(10.),
         })
     }
 }
 
+/// Parse a non-negative percentage
+fn parse_threshold(s: &str) -> anyhow::Result<f64> {
+    match s.trim_end_matches('%').parse()? {
+        threshold if threshold >= 0. => Ok(threshold),
+        _ => Err(anyhow::anyhow!("the threshold must not be negative")),
+    }
+}
+
+/// Remove an option whose value may be omitted from the raw arguments
+///
+/// The value is either attached with `=` or taken from the next argument, unless that is another option.
+/// Returns [`None`] if the option is not present and `Some(None)` if it has no value.
+fn take_optional_value(args: &mut Vec<OsString>, keys: [&str; 2]) -> Option<Option<String>> {
+    for (index, arg) in args.iter().enumerate() {
+        let arg = arg.to_string_lossy();
+
+        for key in keys {
+            if arg == key {
+                let value = args
+                    .get(index + 1)
+                    .map(|value| value.to_string_lossy().into_owned())
+                    .filter(|value| !value.starts_with('-'));
+
+                let remove = if value.is_some() { 2 } else { 1 };
+                args.drain(index..index + remove);
+
+                return Some(value);
+            } else if let Some(value) = arg.strip_prefix(key).and_then(|v| v.strip_prefix('=')) {
+                let value = value.to_string();
+                args.remove(index);
+
+                return Some(Some(value));
+            }
+        }
+    }
+
+    None
+}
+
 /// Runs the app
-pub fn app(quizzes: &[(Box<dyn Quizzer>, &str)]) -> Result<(), anyhow::Error> {
-    let args = Args::try_from_pico_args()?;
+///
+/// Returns whether the run succeeded, i.e. no answer failed verification.
+pub fn app(quizzes: &[Day]) -> Result<bool, anyhow::Error> {
+    let mut args: Vec<_> = std::env::args_os().skip(1).collect();
+
+    match args.first().and_then(|command| command.to_str()) {
+        Some("fetch") => return fetch::command(args.split_off(1)),
+        Some("new") => return scaffold::command(args.split_off(1)),
+        Some("submit") => return submit::command(quizzes, args.split_off(1)),
+        _ => {}
+    }
+
+    let args = Args::try_from_vec(args)?;
 
     if args.help {
         help(stdout());
-        return Ok(());
+        return Ok(true);
     }
 
     let single_quiz = if args.latest_only {
-        quizzes.last().map(|d| (quizzes.len(), d))
-    } else if let Some(single_quiz) = args.single_quiz {
-        Some((
-            single_quiz,
+        quizzes.last()
+    } else if let Some(day) = args.single_quiz {
+        Some(
             quizzes
-                .get(single_quiz - 1)
-                .ok_or_else(|| anyhow::anyhow!("invalid quiz: {}", single_quiz))?,
-        ))
+                .iter()
+                .find(|quiz| quiz.day == day)
+                .ok_or_else(|| anyhow::anyhow!("no quiz for day {}", day))?,
+        )
     } else {
         None
     };
 
-    let mut total = Duration::default();
+    let parts = selected_parts(args.part)?;
+
+    if args.jobs == 0 {
+        return Err(anyhow::anyhow!("--jobs must be positive"));
+    }
+
+    if args.examples && (args.input.is_some() || args.check || args.bench.is_some()) {
+        return Err(anyhow::anyhow!(
+            "--examples can not be combined with --input, --check or --bench"
+        ));
+    }
+
+    if args.watch && (args.examples || args.bench.is_some()) {
+        return Err(anyhow::anyhow!(
+            "--watch can not be combined with --examples or --bench"
+        ));
+    }
+
+    if args.watch
+        && args
+            .input
+            .as_deref()
+            .is_none_or(|path| path == Path::new("-"))
+    {
+        return Err(anyhow::anyhow!("--watch requires an --input file"));
+    }
+
+    if args.visualize.is_some() && (args.examples || args.bench.is_some() || args.watch) {
+        return Err(anyhow::anyhow!(
+            "--visualize can not be combined with --examples, --bench or --watch"
+        ));
+    }
+
+    if args.visualize.is_some() && single_quiz.is_none() {
+        return Err(anyhow::anyhow!(
+            "--visualize requires --single-quiz or --latest-only"
+        ));
+    }
 
-    if let Some((quiz_nr, (quiz, input))) = single_quiz {
-        total += run_quiz(quiz_nr, quiz.as_ref(), input);
+    if args.input.is_some() && single_quiz.is_none() {
+        return Err(anyhow::anyhow!(
+            "--input requires --single-quiz or --latest-only"
+        ));
+    }
+
+    let inputs = Inputs {
+        file: args.input,
+        dir: Some(args.input_dir),
+    };
+
+    let answers = if args.check {
+        Some(Answers::load(&args.answers)?)
     } else {
-        for (index, (quiz, input)) in quizzes.iter().enumerate() {
-            total += run_quiz(index + 1, quiz.as_ref(), input);
+        None
+    };
+
+    let history = match &args.compare {
+        Some(_) => History::load(&args.history)?,
+        None => History::default(),
+    };
+
+    let baseline = match &args.compare {
+        Some(Some(key)) => Some(
+            history
+                .baseline(Some(key))
+                .ok_or_else(|| anyhow::anyhow!("no run '{}' in the history", key))?,
+        ),
+        Some(None) => history.baseline(None),
+        None => None,
+    };
+
+    let entries: Vec<_> = match single_quiz {
+        Some(entry) => vec![entry],
+        None => quizzes.iter().collect(),
+    };
+
+    if let Some(budget) = args.bench {
+        for entry in entries {
+            match inputs.load(entry) {
+                Ok(input) => {
+                    input::warn(entry, &input);
+                    bench_quiz(entry, &input, parts, budget);
+                }
+                Err(e) => eprintln!("quiz{:0>2} skipped: {:#}\n", entry.day, e),
+            }
         }
+
+        return Ok(true);
     }
 
-    println!(
-        "Total execution time: {:>7.2} ms",
-        total.as_secs_f64() * 1000.
-    );
+    if let (Some(target), Some(entry)) = (&args.visualize, single_quiz) {
+        let input = inputs.load(entry)?;
+        input::warn(entry, &input);
+        visualize_quiz(entry, &input, parts, target)?;
 
-    Ok(())
+        return Ok(true);
+    }
+
+    if args.alloc_stats {
+        alloc::enable();
+    }
+
+    if let (true, Some(entry), Some(path)) = (args.watch, single_quiz, &inputs.file) {
+        return watch(
+            entry,
+            path,
+            parts,
+            args.timeout,
+            answers.as_ref(),
+            args.format,
+        );
+    }
+
+    if args.examples {
+        let mut reporter = Reporter::new(args.format, stdout().lock())?;
+        let passed = run_examples(&entries, parts, args.timeout, &mut reporter)?;
+
+        if !passed {
+            eprintln!("Some examples did not pass");
+        }
+
+        return Ok(passed);
+    }
+
+    // the input of a single quiz is required, all others are skipped if they are missing
+    let inputs: Vec<_> = match single_quiz {
+        Some(entry) => vec![Ok(Arc::new(inputs.load(entry)?))],
+        None => entries
+            .iter()
+            .map(|entry| inputs.load(entry).map(Arc::new))
+            .collect(),
+    };
+
+    for (entry, input) in entries.iter().zip(&inputs) {
+        if let Ok(input) = input {
+            input::warn(entry, input);
+        }
+    }
+
+    let parsed: Vec<_> = entries.iter().map(|_| OnceLock::new()).collect();
+    let jobs = jobs(&entries, &inputs, &parsed, parts, args.parallel_parts);
+
+    let mut reporter = Reporter::new(args.format, stdout().lock())?;
+    let mut total = Duration::default();
+    let mut passed = true;
+    let mut steps = Vec::new();
+
+    let start = Instant::now();
+
+    let timeout = args.timeout;
+
+    pool::ordered(
+        args.jobs,
+        &jobs,
+        |job| job.run(timeout),
+        |job, results| {
+            let Day { day, title, .. } = job.entry;
+
+            let results = match (results, job.input) {
+                (Some(results), _) => results,
+                (None, Err(e)) if job.is_first() => return reporter.skipped(*day, parts, e),
+                (None, _) => return Ok(()),
+            };
+
+            if job.is_first() {
+                reporter.quiz(*day, title)?;
+
+                if let Some(parsed) = job.parsed.get() {
+                    total += parsed.elapsed;
+                    reporter.parse(parsed)?;
+
+                    if parsed.parsed.is_ok() {
+                        steps.push(Step {
+                            day: *day,
+                            step: String::from("parse"),
+                            duration_ns: nanos(parsed.elapsed),
+                        });
+                    }
+                }
+            }
+
+            for result in &results {
+                let verdict = answers.as_ref().map(|answers| result.verify(answers));
+                passed &= !verdict.as_ref().is_some_and(Verdict::is_failure);
+                total += result.elapsed;
+                reporter.part(result, verdict.as_ref())?;
+
+                if result.answer.is_ok() {
+                    steps.push(Step {
+                        day: *day,
+                        step: format!("part{}", result.part),
+                        duration_ns: nanos(result.elapsed),
+                    });
+                }
+            }
+
+            if job.is_last() {
+                reporter.end_quiz()?;
+            }
+
+            Ok(())
+        },
+    )?;
+
+    reporter.summary(total, start.elapsed(), passed)?;
+
+    let run = Run::new(steps);
+
+    match baseline {
+        Some(baseline) => {
+            let comparison = Comparison {
+                baseline,
+                current: &run,
+                threshold: args.threshold,
+            };
+
+            // keep machine-readable output parseable
+            if args.format == Format::Text {
+                print!("\n{}", comparison);
+            } else {
+                eprint!("{}", comparison);
+            }
+
+            match comparison.regressions() {
+                0 => {}
+                n => eprintln!("{} steps slowed down by more than {}%", n, args.threshold),
+            }
+        }
+        None if args.compare.is_some() => eprintln!("No previous run to compare to"),
+        None => {}
+    }
+
+    if !args.no_history {
+        History::append(&args.history, &run)?;
+    }
+
+    if !passed {
+        eprintln!("Some answers did not pass verification");
+    }
+
+    Ok(passed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        assert_eq!(selected_parts(None).unwrap(), &[1, 2]);
+        assert_eq!(selected_parts(Some(2)).unwrap(), &[2]);
+        assert!(selected_parts(Some(3)).is_err());
+    }
+
+    #[test]
+    fn examples() {
+        let entries: Vec<_> = [1, 6].into_iter().filter_map(aoc21::days::get).collect();
+
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(Format::Csv, &mut out).unwrap();
+        assert!(run_examples(&entries, &[1, 2], None, &mut reporter).unwrap());
+        assert_eq!(
+            String::from_utf8(out)
+                .unwrap()
+                .lines()
+                .filter(|line| line.ends_with(",pass"))
+                .count(),
+            4
+        );
+
+        /// A quiz whose example expects a different answer
+        struct Wrong;
+
+        impl aoc21::Quizzer for Wrong {
+            type Parsed = ();
+
+            fn parse(&self, _input: &str) -> anyhow::Result<()> {
+                Ok(())
+            }
+
+            fn part1(&self, _parsed: &()) -> anyhow::Result<Answer> {
+                Ok(Answer::Int(1))
+            }
+
+            fn examples(&self) -> &'static [aoc21::Example] {
+                &[aoc21::Example {
+                    input: "",
+                    part1: Some("2"),
+                    part2: None,
+                }]
+            }
+        }
+
+        let wrong = Day {
+            day: 1,
+            title: "Wrong",
+            quiz: &Wrong,
+            embedded_input: None,
+        };
+
+        let mut reporter = Reporter::new(Format::Text, Vec::new()).unwrap();
+        assert!(!run_examples(&[&wrong], &[1, 2], None, &mut reporter).unwrap());
+    }
+
+    #[test]
+    fn rerun_highlights_changes() {
+        let entry = aoc21::days::get(6).unwrap();
+
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(Format::Text, &mut out).unwrap();
+        let first = rerun(entry, "3,4,3,1,2", &[1, 2], None, None, &[], &mut reporter).unwrap();
+        assert_eq!(first[0], (1, Answer::Int(5934)));
+
+        let same = rerun(
+            entry,
+            "3,4,3,1,2",
+            &[1, 2],
+            None,
+            None,
+            &first,
+            &mut reporter,
+        )
+        .unwrap();
+        assert_eq!(same, first);
+
+        let changed = rerun(entry, "3,4,3,1", &[1], None, None, &same, &mut reporter).unwrap();
+        assert_ne!(changed[0], first[0]);
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("CHANGED").count(), 1);
+        assert!(out.contains("quiz06-part1 CHANGED, was 5934\n"));
+    }
 }
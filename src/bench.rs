@@ -0,0 +1,213 @@
+//! Repeated measurements of quiz execution times
+use std::fmt;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How long each part of a quiz is benchmarked
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Budget {
+    /// Run each part a fixed number of times
+    Runs(usize),
+    /// Run each part repeatedly until the time is used up
+    Time(Duration),
+}
+
+impl Budget {
+    /// The warm-up budget, a tenth of the measurement budget
+    fn warm_up(&self) -> Self {
+        match self {
+            Budget::Runs(runs) => Budget::Runs((runs / 10).max(1)),
+            Budget::Time(time) => Budget::Time(*time / 10),
+        }
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget::Runs(100)
+    }
+}
+
+impl FromStr for Budget {
+    type Err = anyhow::Error;
+
+    /// Parses either a number of runs (`100`) or a time budget (`500ms`, `2s`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let budget = if let Some(ms) = s.strip_suffix("ms") {
+            Budget::Time(Duration::from_millis(ms.parse()?))
+        } else if let Some(secs) = s.strip_suffix('s') {
+            Budget::Time(Duration::try_from_secs_f64(secs.parse()?)?)
+        } else {
+            Budget::Runs(s.parse()?)
+        };
+
+        match budget {
+            Budget::Runs(0) => Err(anyhow::anyhow!("the number of runs must be positive")),
+            Budget::Time(time) if time.is_zero() => {
+                Err(anyhow::anyhow!("the time budget must be positive"))
+            }
+            budget => Ok(budget),
+        }
+    }
+}
+
+/// Summary statistics of a set of measurements
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    /// The number of measurements
+    pub runs: usize,
+    /// The fastest measurement
+    pub min: Duration,
+    /// The median measurement
+    pub median: Duration,
+    /// The arithmetic mean of all measurements
+    pub mean: Duration,
+    /// The sample standard deviation of all measurements
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculate the statistics of the given measurements
+    ///
+    /// Returns [`None`] if there are no measurements.
+    pub fn new(samples: &mut [Duration]) -> Option<Self> {
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let min = *samples.first()?;
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+
+        let secs: Vec<_> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.
+        };
+
+        Some(Self {
+            runs,
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Format a duration as fractional microseconds
+        fn us(d: Duration) -> f64 {
+            d.as_secs_f64() * 1e6
+        }
+
+        write!(
+            f,
+            "runs {:>6}  min {:>10.1} us  median {:>10.1} us  mean {:>10.1} us  stddev {:>9.1} us",
+            self.runs,
+            us(self.min),
+            us(self.median),
+            us(self.mean),
+            us(self.stddev)
+        )
+    }
+}
+
+/// Repeatedly measure a closure, after warming up, until the budget is used up
+///
+/// Each run calls the closure from scratch, so no state can be carried over from one run to the next.
+/// Inputs and outputs pass through [`black_box`] to keep the compiler from optimizing runs away.
+///
+/// # Errors
+/// Returns the first error of the closure.
+pub fn measure<F, T>(budget: Budget, func: F) -> anyhow::Result<Stats>
+where
+    F: Fn() -> anyhow::Result<T>,
+{
+    run(budget.warm_up(), &func)?;
+
+    let mut samples = run(budget, &func)?;
+    Stats::new(&mut samples).ok_or_else(|| anyhow::anyhow!("no measurements taken"))
+}
+
+/// Run the closure until the budget is used up and collect the execution times
+fn run<F, T>(budget: Budget, func: &F) -> anyhow::Result<Vec<Duration>>
+where
+    F: Fn() -> anyhow::Result<T>,
+{
+    let mut samples = Vec::new();
+    let begin = Instant::now();
+
+    loop {
+        let done = match budget {
+            Budget::Runs(runs) => samples.len() >= runs,
+            Budget::Time(time) => !samples.is_empty() && begin.elapsed() >= time,
+        };
+
+        if done {
+            return Ok(samples);
+        }
+
+        let start = Instant::now();
+        black_box(black_box(func)()?);
+        samples.push(start.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget() {
+        assert_eq!("25".parse::<Budget>().unwrap(), Budget::Runs(25));
+        assert_eq!(
+            "500ms".parse::<Budget>().unwrap(),
+            Budget::Time(Duration::from_millis(500))
+        );
+        assert_eq!(
+            "1.5s".parse::<Budget>().unwrap(),
+            Budget::Time(Duration::from_millis(1500))
+        );
+        assert!("0".parse::<Budget>().is_err());
+        assert!("0s".parse::<Budget>().is_err());
+        assert!("fast".parse::<Budget>().is_err());
+    }
+
+    #[test]
+    fn stats() {
+        let mut samples: Vec<_> = [4, 1, 3, 2]
+            .into_iter()
+            .map(Duration::from_micros)
+            .collect();
+        let stats = Stats::new(&mut samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        // sqrt(5 / 3) us, up to rounding
+        assert!((1290..=1291).contains(&stats.stddev.as_nanos()));
+
+        assert_eq!(Stats::new(&mut []), None);
+    }
+
+    #[test]
+    fn measure_runs() {
+        let calls = std::cell::Cell::new(0);
+        let stats = measure(Budget::Runs(20), || {
+            calls.set(calls.get() + 1);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(stats.runs, 20);
+        assert_eq!(calls.get(), 22);
+    }
+}
@@ -0,0 +1,168 @@
+//! Verification of quiz results against known answers
+use anyhow::Context;
+use aoc21::Answer;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The expected answers of all quizzes, keyed by day and part
+///
+/// The answers file contains one `DAY PART ANSWER` line per day and part.
+/// Empty lines and lines starting with `#` are ignored, newlines within an answer are written as `\n`.
+///
+/// ```txt
+/// # day part answer
+/// 1 1 1548
+/// 1 2 1589
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers(HashMap<(usize, usize), String>);
+
+impl Answers {
+    /// Load the answers from a file
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("reading answers {} failed", path.display()))?
+            .parse()
+            .with_context(|| format!("parsing answers {} failed", path.display()))
+    }
+
+    /// The expected answer of the given day and part, if there is one
+    pub fn expected(&self, day: usize, part: usize) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Check an answer against the expected answer of the given day and part
+    pub fn verify(&self, day: usize, part: usize, answer: &Answer) -> Verdict {
+        match self.expected(day, part) {
+            Some(expected) => Verdict::new(expected, answer),
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = HashMap::new();
+
+        for (n, line) in s.lines().map(str::trim).enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (day, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer),
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "line {}: expected 'DAY PART ANSWER'",
+                        n + 1
+                    ))
+                }
+            };
+
+            let day = day
+                .parse()
+                .with_context(|| format!("line {}: invalid day '{}'", n + 1, day))?;
+            let part = part
+                .parse()
+                .with_context(|| format!("line {}: invalid part '{}'", n + 1, part))?;
+
+            answers.insert((day, part), answer.trim().replace("\\n", "\n"));
+        }
+
+        Ok(Self(answers))
+    }
+}
+
+/// The verdict on a single answer
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The answer matches the expected answer
+    Pass,
+    /// The answer does not match the expected answer
+    Fail(String),
+    /// The part failed to produce an answer, although one is expected
+    Error,
+    /// There is no expected answer
+    Missing,
+}
+
+impl Verdict {
+    /// Check an answer against the expected one, as [`Answer::matches`] does
+    pub fn new(expected: &str, answer: &Answer) -> Self {
+        if answer.matches(expected) {
+            Verdict::Pass
+        } else {
+            Verdict::Fail(expected.to_string())
+        }
+    }
+
+    /// Whether the verdict should fail the run
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail(_) | Verdict::Error)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail(expected) => {
+                write!(f, "FAIL (expected {})", expected.replace('\n', "\\n"))
+            }
+            Verdict::Error => f.write_str("FAIL (no answer)"),
+            Verdict::Missing => f.write_str("MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+        # day part answer
+        1 1 1548
+        1 2 1589
+
+        13 2 #..#\\n####";
+
+    #[test]
+    fn verify() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        let bitmap = |dots: Vec<bool>| Answer::Bitmap((dots, 4).try_into().unwrap());
+
+        assert_eq!(answers.verify(1, 1, &Answer::Int(1548)), Verdict::Pass);
+        assert_eq!(
+            answers.verify(1, 2, &Answer::Int(1590)),
+            Verdict::Fail("1589".into())
+        );
+        assert_eq!(answers.verify(2, 1, &Answer::Int(1)), Verdict::Missing);
+        assert_eq!(
+            answers.verify(
+                13,
+                2,
+                &bitmap(vec![true, false, false, true, true, true, true, true])
+            ),
+            Verdict::Pass
+        );
+        assert!(answers
+            .verify(
+                13,
+                2,
+                &bitmap(vec![true, false, false, true, true, true, true, false])
+            )
+            .is_failure());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("1 1".parse::<Answers>().is_err());
+        assert!("x 1 1548".parse::<Answers>().is_err());
+    }
+}
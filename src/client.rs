@@ -0,0 +1,410 @@
+//! A client for the Advent of Code website
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The environment variable holding the session token
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable holding the base URL
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The base URL of the Advent of Code website
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The user agent sent along with each request, as asked for by the Advent of Code website
+const USER_AGENT: &str = concat!(
+    "github.com/allgoewer/aoc-2021 aoc21-cli/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// The default minimum interval between two requests
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The session file in the users config directory, if there is a config directory
+pub fn default_session_file() -> Option<PathBuf> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config.join("aoc21").join("session"))
+}
+
+/// Get the session token from the environment or, if it is not set there, from the session file
+pub fn session_token(file: Option<&Path>) -> anyhow::Result<String> {
+    if let Some(token) = std::env::var(SESSION_VAR)
+        .ok()
+        .filter(|t| !t.trim().is_empty())
+    {
+        return Ok(token.trim().to_string());
+    }
+
+    let file = file.ok_or_else(|| {
+        anyhow::anyhow!(
+            "no session token, set {} or use a session file",
+            SESSION_VAR
+        )
+    })?;
+
+    let token = fs::read_to_string(file)
+        .with_context(|| format!("reading session token {} failed", file.display()))?;
+
+    match token.trim() {
+        "" => Err(anyhow::anyhow!("session file {} is empty", file.display())),
+        token => Ok(token.to_string()),
+    }
+}
+
+/// The command line options of the commands talking to the Advent of Code website
+#[derive(Debug)]
+pub struct Options {
+    /// Option to set the base URL of the Advent of Code website
+    pub base_url: String,
+    /// Option to read the session token from a file
+    pub session_file: Option<PathBuf>,
+    /// Option to set the minimum interval between two requests
+    pub interval: Duration,
+}
+
+impl Options {
+    /// Take the options from the command line arguments
+    pub fn from_args(args: &mut pico_args::Arguments) -> anyhow::Result<Self> {
+        Ok(Self {
+            base_url: match args.opt_value_from_str("--base-url")? {
+                Some(url) => url,
+                None => {
+                    std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+                }
+            },
+            session_file: args
+                .opt_value_from_str("--session-file")?
+                .or_else(default_session_file),
+            interval: args
+                .opt_value_from_fn("--interval", parse_interval)?
+                .unwrap_or(DEFAULT_INTERVAL),
+        })
+    }
+
+    /// Create a client which keeps the time of its last request in the given stamp file
+    pub fn client(&self, stamp: PathBuf) -> anyhow::Result<Client> {
+        let session = session_token(self.session_file.as_deref())?;
+
+        Ok(Client::new(&self.base_url, session, self.interval, stamp))
+    }
+}
+
+/// Parse an interval given in (fractional) seconds
+fn parse_interval(s: &str) -> anyhow::Result<Duration> {
+    Ok(Duration::try_from_secs_f64(s.parse()?)?)
+}
+
+/// A client for the Advent of Code website which keeps a minimum interval between requests
+///
+/// The time of the last request is kept in a stamp file, so the interval holds across invocations.
+pub struct Client {
+    /// The base URL, without a trailing slash
+    base_url: String,
+    /// The session token
+    session: String,
+    /// The minimum interval between two requests
+    interval: Duration,
+    /// The file holding the time of the last request
+    stamp: PathBuf,
+    /// The HTTP agent
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Create a new client
+    pub fn new(base_url: &str, session: String, interval: Duration, stamp: PathBuf) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            interval,
+            stamp,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Download the input of the given day
+    pub fn input(&self, day: usize) -> anyhow::Result<String> {
+        let url = format!("{}/2021/day/{}/input", self.base_url, day);
+        self.throttle()?;
+
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+
+        read(response).with_context(|| format!("fetching {} failed", url))
+    }
+
+    /// Submit an answer to a part of the quiz of the given day
+    ///
+    /// Returns the outcome together with the message of the server.
+    pub fn submit(
+        &self,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> anyhow::Result<(Outcome, String)> {
+        let url = format!("{}/2021/day/{}/answer", self.base_url, day);
+        self.throttle()?;
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let message = read(response)
+            .map(|body| message(&body))
+            .with_context(|| format!("submitting to {} failed", url))?;
+
+        match Outcome::parse(&message) {
+            Some(outcome) => Ok((outcome, message)),
+            None => Err(anyhow::anyhow!("unexpected response: {}", message)),
+        }
+    }
+
+    /// The session cookie
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Wait until the minimum interval since the last request has passed and note the time of this request
+    fn throttle(&self) -> anyhow::Result<()> {
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse().ok())
+            .map(Duration::from_millis);
+
+        if let Some(wait) = last.and_then(|last| (last + self.interval).checked_sub(now())) {
+            eprintln!(
+                "waiting {:.1} s before the next request",
+                wait.as_secs_f64()
+            );
+            thread::sleep(wait);
+        }
+
+        fs::write(&self.stamp, now().as_millis().to_string())
+            .with_context(|| format!("writing {} failed", self.stamp.display()))
+    }
+}
+
+/// Read the body of a response, turning error statuses into errors
+fn read(response: Result<ureq::Response, ureq::Error>) -> anyhow::Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(anyhow::anyhow!(
+                "the server responded with {}: {}",
+                status,
+                body.lines().next().unwrap_or_default()
+            ))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// The text of the `<article>` of a page, or of the whole page if there is none, without markup
+fn message(body: &str) -> String {
+    let article = body
+        .split_once("<article")
+        .and_then(|(_, article)| article.split_once('>'))
+        .and_then(|(_, article)| article.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The outcome of submitting an answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// The answer is correct
+    Correct,
+    /// The answer is wrong
+    Wrong,
+    /// The answer is wrong, it is too high
+    TooHigh,
+    /// The answer is wrong, it is too low
+    TooLow,
+    /// The answer was not checked, because the last one was submitted too recently
+    RateLimited,
+    /// The answer was not checked, because the part is solved already or not unlocked yet
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Recognize the outcome in the message of the server
+    fn parse(message: &str) -> Option<Self> {
+        let outcome = if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("your answer is too high") {
+            Self::TooHigh
+        } else if message.contains("your answer is too low") {
+            Self::TooLow
+        } else if message.contains("That's not the right answer") {
+            Self::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Self::RateLimited
+        } else if message.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            return None;
+        };
+
+        Some(outcome)
+    }
+
+    /// Whether the answer was checked by the server
+    pub fn is_checked(self) -> bool {
+        !matches!(self, Self::RateLimited | Self::WrongLevel)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::RateLimited => "rate limited",
+            Self::WrongLevel => "wrong level",
+        })
+    }
+}
+
+/// A stub of the Advent of Code website for tests
+#[cfg(test)]
+pub mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve a single request with the given body on a local port
+    ///
+    /// Returns the base URL of the server and a handle returning the request, head and body, once it was served.
+    pub fn server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&mut stream);
+            let mut request = String::new();
+            let mut length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(": ") {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.parse().unwrap();
+                    }
+                }
+
+                request.push_str(line);
+                request.push('\n');
+            }
+
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push('\n');
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, server)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcomes() {
+        let page = |article: &str| {
+            message(&format!(
+                "<html><body><main><article><p>{}</p></article></main></body></html>",
+                article
+            ))
+        };
+
+        let wrong = page("That's not the right answer; your answer is too low. <a href=\"/2021/day/1\">[Return]</a>");
+        assert_eq!(
+            wrong,
+            "That's not the right answer; your answer is too low. [Return]"
+        );
+        assert_eq!(Outcome::parse(&wrong), Some(Outcome::TooLow));
+
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Outcome::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have 42s left to wait.",
+                Outcome::RateLimited,
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                Outcome::WrongLevel,
+            ),
+        ];
+
+        for (article, outcome) in cases {
+            assert_eq!(Outcome::parse(&page(article)), Some(outcome));
+        }
+
+        assert_eq!(Outcome::parse(&page("Please log in")), None);
+    }
+}
@@ -0,0 +1,154 @@
+//! The quizzes of all days of the advent
+//!
+//! ```
+//! use aoc21::{days, Answer};
+//!
+//! assert_eq!(days::get(1).map(|day| day.title), Some("Sonar Sweep"));
+//! assert_eq!(days::solve(1, 1, "199\n200\n208\n210\n200").unwrap(), Answer::Int(3));
+//! ```
+use crate::{Answer, DynQuizzer};
+use std::sync::OnceLock;
+
+pub mod quiz01;
+pub mod quiz02;
+pub mod quiz03;
+pub mod quiz04;
+pub mod quiz06;
+pub mod quiz07;
+pub mod quiz08;
+pub mod quiz09;
+pub mod quiz10;
+pub mod quiz11;
+pub mod quiz13;
+pub mod quiz14;
+pub mod quiz15;
+pub mod quiz16;
+
+/// Compose the quizzes and their respective inputs into a list of [`Day`]s
+///
+/// The day and title of each quiz are taken from the `//! Day N: Title` header of its module.
+/// The inputs are only embedded if the `embedded-inputs` feature is enabled.
+macro_rules! days {
+    ($($module:ident: $input:expr),+ $(,)?) => {
+        vec![$({
+            let (day, title) = parse_header(include_str!(concat!("days/", stringify!($module), ".rs")))
+                .expect(concat!("no '//! Day N: Title' header in ", stringify!($module)));
+
+            #[cfg(feature = "embedded-inputs")]
+            let embedded_input = Some(include_str!($input));
+            #[cfg(not(feature = "embedded-inputs"))]
+            let embedded_input = None;
+
+            Day {
+                day,
+                title,
+                quiz: &$module::Quiz,
+                embedded_input,
+            }
+        }),+]
+    };
+}
+
+/// A quiz together with the day it belongs to and its input
+#[derive(Clone, Copy)]
+pub struct Day {
+    /// The day of the Advent of Code the quiz belongs to
+    pub day: usize,
+    /// The title of the quiz
+    pub title: &'static str,
+    /// The quiz implementation
+    pub quiz: &'static dyn DynQuizzer,
+    /// The quizzes input as embedded into the library
+    pub embedded_input: Option<&'static str>,
+}
+
+/// All available quizzes, ordered by day
+pub fn all() -> &'static [Day] {
+    /// The quizzes, composed on first use
+    static DAYS: OnceLock<Vec<Day>> = OnceLock::new();
+
+    DAYS.get_or_init(|| {
+        let mut days = days![
+            quiz01: "inputs/1",
+            quiz02: "inputs/2",
+            quiz03: "inputs/3",
+            quiz04: "inputs/4",
+            quiz06: "inputs/6",
+            quiz07: "inputs/7",
+            quiz08: "inputs/8",
+            quiz09: "inputs/9",
+            quiz10: "inputs/10",
+            quiz11: "inputs/11",
+            quiz13: "inputs/13",
+            quiz14: "inputs/14",
+            quiz15: "inputs/15",
+            quiz16: "inputs/16",
+        ];
+
+        days.sort_by_key(|day| day.day);
+        days
+    })
+}
+
+/// The quiz of the given day, if there is one
+pub fn get(day: usize) -> Option<&'static Day> {
+    all().iter().find(|quiz| quiz.day == day)
+}
+
+/// Solve a part of the quiz of the given day for an input
+///
+/// # Errors
+/// Returns an error if there is no quiz for the day or no such part, or if the quiz fails on the input.
+pub fn solve(day: usize, part: usize, input: &str) -> anyhow::Result<Answer> {
+    let quiz = get(day)
+        .ok_or_else(|| anyhow::anyhow!("no quiz for day {}", day))?
+        .quiz;
+
+    if !(1..=2).contains(&part) {
+        return Err(anyhow::anyhow!("there is no part {}, only 1 and 2", part));
+    }
+
+    let parsed = quiz.parse(input)?;
+
+    match part {
+        1 => quiz.part1(&parsed),
+        _ => quiz.part2(&parsed),
+    }
+}
+
+/// Parse the day and title from the `//! Day N: Title` header of a quiz module
+pub fn parse_header(source: &'static str) -> Option<(usize, &'static str)> {
+    let header = source.lines().next()?.strip_prefix("//! Day ")?;
+    let (day, title) = header.split_once(':')?;
+
+    Some((day.trim().parse().ok()?, title.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header() {
+        assert_eq!(
+            parse_header("//! Day 6: Lanternfish\nuse crate::Quizzer;"),
+            Some((6, "Lanternfish"))
+        );
+        assert_eq!(
+            parse_header("//! Day 13: Transparent Origami"),
+            Some((13, "Transparent Origami"))
+        );
+        assert_eq!(parse_header("//! Application logic"), None);
+        assert_eq!(parse_header("//! Day x: Lanternfish"), None);
+    }
+
+    #[test]
+    fn days() {
+        let days: Vec<_> = all().iter().map(|day| day.day).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(solve(6, 1, "3,4,3,1,2").unwrap(), Answer::Int(5934));
+        assert!(solve(6, 3, "3,4,3,1,2").is_err());
+        assert!(solve(26, 1, "").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+//! Day 1: Sonar Sweep
+use crate::{util::*, Answer, Example, Quizzer};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = Vec<i32>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        collected(input)
+    }
+
+    fn part1(&self, depths: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(count_increases(depths, 2).into())
+    }
+
+    fn part2(&self, depths: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(count_increases(depths, 4).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("7"),
+            part2: Some("5"),
+        }]
+    }
+}
+
+/// Count the number of increases in depth from one step to another
+fn count_increases(values: &[i32], len: usize) -> usize {
+    // w[1] + .. + w[len - 2] cancels out on both sides of the equation
+    values.windows(len).filter(|w| w.first() < w.last()).count()
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(count_increases(&collected(EXAMPLE).unwrap(), 2), 7);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(count_increases(&collected(EXAMPLE).unwrap(), 4), 5);
+    }
+}
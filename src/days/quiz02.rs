@@ -1,23 +1,37 @@
 //! Day 2: Dive!
-use aoc21::{util::*, Quizzer};
+use crate::{util::*, Answer, Example, Quizzer};
 use std::str::FromStr;
 
 /// Todays quiz implementation
 pub struct Quiz;
 
 impl Quizzer for Quiz {
-    fn part1(&self, input: &str) -> String {
-        calc_depth_result(parsed(input)).to_string()
+    type Parsed = Vec<Command>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        collected(input)
+    }
+
+    fn part1(&self, cmds: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(calc_depth_result(cmds.iter().copied()).into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        calc_complicated_depth_result(parsed(input)).to_string()
+    fn part2(&self, cmds: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(calc_complicated_depth_result(cmds.iter().copied()).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("150"),
+            part2: Some("900"),
+        }]
     }
 }
 
 /// Submarine movement command
-#[derive(Debug)]
-struct Command(i64, i64);
+#[derive(Clone, Copy, Debug)]
+pub struct Command(i64, i64);
 
 impl FromStr for Command {
     type Err = anyhow::Error;
@@ -51,25 +65,28 @@ fn calc_complicated_depth_result(cmds: impl Iterator<Item = Command>) -> i64 {
     x * y
 }
 
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    forward 5
+    down 5
+    forward 8
+    up 3
+    down 8
+    forward 2";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = "\
-        forward 5
-        down 5
-        forward 8
-        up 3
-        down 8
-        forward 2";
-
     #[test]
     fn part1_examples() {
-        assert_eq!(calc_depth_result(parsed(EXAMPLE)), 150);
+        let cmds = collected(EXAMPLE).unwrap();
+        assert_eq!(calc_depth_result(cmds.into_iter()), 150);
     }
 
     #[test]
     fn part2_examples() {
-        assert_eq!(calc_complicated_depth_result(parsed(EXAMPLE)), 900);
+        let cmds = collected(EXAMPLE).unwrap();
+        assert_eq!(calc_complicated_depth_result(cmds.into_iter()), 900);
     }
 }
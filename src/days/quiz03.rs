@@ -0,0 +1,202 @@
+//! Day 3: Binary Diagnostic
+use crate::{util::*, Answer, Example, Quizzer};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = (Vec<u16>, usize);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let bits = bits(input)?;
+
+        Ok((collect(input)?, bits))
+    }
+
+    fn part1(&self, (values, bits): &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(part1(values, *bits).into())
+    }
+
+    fn part2(&self, (values, bits): &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(part2(values.clone(), *bits)?.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("198"),
+            part2: Some("230"),
+        }]
+    }
+
+    fn grid_input(&self) -> bool {
+        true
+    }
+}
+
+/// Collect the input
+fn collect(input: &str) -> anyhow::Result<Vec<u16>> {
+    let values = collected_with(input, |v| u16::from_str_radix(v, 2))?;
+
+    if values.is_empty() {
+        return Err(anyhow::anyhow!("the diagnostic report is empty"));
+    }
+
+    Ok(values)
+}
+
+/// The number of bits of the values in the input, i.e. the length of its longest line
+///
+/// # Errors
+/// Returns an error for a line longer than the bits of the [`u16`] values.
+fn bits(input: &str) -> anyhow::Result<usize> {
+    let mut bits = 0;
+
+    for (n, line) in input.lines().enumerate() {
+        let len = line.trim().len();
+
+        if len > u16::BITS as usize {
+            return Err(anyhow::anyhow!(
+                "line {}: {} bits are more than the {} supported",
+                n + 1,
+                len,
+                u16::BITS
+            ));
+        }
+
+        bits = bits.max(len);
+    }
+
+    Ok(bits)
+}
+
+/// Count the number of 1s for all bit positions in the input and returns the length of the iterator
+fn count_ones<'a>(values: impl IntoIterator<Item = &'a u16>, counter: &mut [usize]) -> usize {
+    counter.iter_mut().for_each(|v| *v = 0);
+
+    values
+        .into_iter()
+        .copied()
+        .inspect(|v| {
+            for (i, one_count) in counter.iter_mut().enumerate() {
+                if (v >> i) & 1 != 0 {
+                    *one_count += 1;
+                }
+            }
+        })
+        .count()
+}
+
+/// Calculate the the submarines power consumption according to part 1
+fn part1(values: &[u16], bits: usize) -> usize {
+    let mut one_counts = vec![0; bits];
+    let n_entries = count_ones(values, &mut one_counts);
+
+    let (gamma, epsilon) =
+        one_counts
+            .iter()
+            .enumerate()
+            .fold((0, 0), |(gamma, epsilon), (i, &one_count)| {
+                if one_count > n_entries - one_count {
+                    (gamma | 1 << i, epsilon)
+                } else {
+                    (gamma, epsilon | 1 << i)
+                }
+            });
+
+    gamma * epsilon
+}
+
+/// Calculate the submarines oxygen generator rating and co2 scrubber rating according to part 2
+fn part2(values: Vec<u16>, n_bits: usize) -> anyhow::Result<usize> {
+    let mut one_counts = vec![0; n_bits];
+    let mut ogr_values = values.clone();
+    let mut csr_values = values;
+
+    for b in (0..n_bits).rev() {
+        let bit = 1 << b;
+
+        // update bit counts for the oxygen generator rating
+        let n_entries = count_ones(&ogr_values, &mut one_counts);
+        let ones = one_counts[b];
+        let zeros = n_entries - ones;
+
+        if ogr_values.len() > 1 {
+            ogr_values.retain(|v| {
+                if ones >= zeros {
+                    v & bit != 0
+                } else {
+                    v & bit == 0
+                }
+            });
+        }
+
+        // update bit counts for the co2 scrubber rating
+        let n_entries = count_ones(&csr_values, &mut one_counts);
+        let ones = one_counts[b];
+        let zeros = n_entries - ones;
+
+        if csr_values.len() > 1 {
+            csr_values.retain(|v| {
+                if zeros <= ones {
+                    v & bit == 0
+                } else {
+                    v & bit != 0
+                }
+            });
+        }
+    }
+
+    let ogr = ogr_values
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("no oxygen generator rating left"))?;
+    let csr = csr_values
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("no co2 scrubber rating left"))?;
+
+    Ok(*ogr as usize * *csr as usize)
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    00100
+    11110
+    10110
+    10111
+    10101
+    01111
+    00111
+    11100
+    10000
+    11001
+    00010
+    01010";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&collect(EXAMPLE).unwrap(), 5), 198);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(part2(collect(EXAMPLE).unwrap(), 5).unwrap(), 230);
+    }
+
+    #[test]
+    fn too_many_bits() {
+        assert_eq!(bits(EXAMPLE).unwrap(), 5);
+
+        let err = bits("00100\n00000000000000001").unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+    }
+
+    #[test]
+    fn empty_report() {
+        assert!(collect("").is_err());
+        assert!(part2(Vec::new(), 5).is_err());
+    }
+}
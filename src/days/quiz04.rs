@@ -1,19 +1,32 @@
 //! Day 4: Giant Squid
-use aoc21::{util::*, Quizzer};
-use std::{convert::Infallible, str::FromStr};
+use crate::{util::*, Answer, Example, Quizzer};
+use anyhow::Context;
+use std::str::FromStr;
 
 /// Todays quiz implementation
 pub struct Quiz;
 
 impl Quizzer for Quiz {
-    fn part1(&self, input: &str) -> String {
-        let mut bingo: Bingo = input.parse().unwrap();
-        bingo.play_winning_score().to_string()
+    type Parsed = Bingo;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(&self, bingo: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(bingo.clone().play_winning_score().into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let mut bingo: Bingo = input.parse().unwrap();
-        bingo.play_last_winning_score().to_string()
+    fn part2(&self, bingo: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(bingo.clone().play_last_winning_score().into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("4512"),
+            part2: Some("1924"),
+        }]
     }
 }
 
@@ -22,7 +35,7 @@ type Board = Grid<Option<u8>>;
 
 /// A game of bingo
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-struct Bingo {
+pub struct Bingo {
     /// All drawn numbers in order of occurrence
     draws: Vec<u8>,
     /// All boards participating in the game
@@ -54,23 +67,8 @@ fn get_winners(boards: &mut Vec<Board>) -> Option<Vec<Board>> {
     let mut winners = Vec::new();
 
     while let Some((index, _)) = boards.iter().enumerate().find(|(_, b)| {
-        let (width, height) = b.dim();
-
-        for col in 0..width {
-            let all_drawn = b.col_iter(col).all(|v| v.is_none());
-            if all_drawn {
-                return true;
-            }
-        }
-
-        for row in 0..height {
-            let all_drawn = b.row_iter(row).all(|v| v.is_none());
-            if all_drawn {
-                return true;
-            }
-        }
-
-        false
+        b.cols().any(|mut col| col.all(Option::is_none))
+            || b.rows().any(|row| row.iter().all(Option::is_none))
     }) {
         winners.push(boards.remove(index));
     }
@@ -104,7 +102,7 @@ impl Bingo {
             mark_draws(&mut self.boards, *draw);
 
             if let Some(winners) = get_winners(&mut self.boards) {
-                winner_list.extend(std::iter::repeat(*draw).zip(winners.into_iter()));
+                winner_list.extend(std::iter::repeat(*draw).zip(winners));
             }
         }
 
@@ -116,65 +114,74 @@ impl Bingo {
 }
 
 impl FromStr for Bingo {
-    type Err = Infallible;
+    type Err = anyhow::Error;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut lines = input.lines().map(str::trim);
+        let mut lines = input.lines().map(str::trim).enumerate();
 
-        let draws: Vec<_> = lines
+        let draws = lines
             .next()
-            .expect("no draws in input")
-            .trim()
+            .ok_or_else(|| anyhow::anyhow!("no draws in input"))?
+            .1
             .split(',')
-            .map(|v| v.parse().expect("parsing draw failed"))
-            .collect();
+            .map(|v| v.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .context("line 1: parsing draws failed")?;
 
         let mut boards = Vec::new();
         let mut next_board = Vec::new();
 
-        for line in lines {
-            let line = line.trim();
-
-            next_board.extend(
-                line.split_ascii_whitespace()
-                    .map(|v| Some(v.parse::<u8>().expect("parsing board value failed"))),
-            );
+        for (n, line) in lines {
+            for v in line.split_ascii_whitespace() {
+                let value = v.parse::<u8>().with_context(|| {
+                    format!("line {}: parsing board value '{}' failed", n + 1, v)
+                })?;
+                next_board.push(Some(value));
+            }
 
             if next_board.len() == 25 {
-                boards.push((next_board.clone(), 5).try_into().expect("invalid board"));
+                boards.push((next_board.clone(), 5).try_into()?);
                 next_board.clear();
             }
         }
 
+        if !next_board.is_empty() {
+            return Err(anyhow::anyhow!(
+                "last board is incomplete ({} of 25 values)",
+                next_board.len()
+            ));
+        }
+
         Ok(Bingo { draws, boards })
     }
 }
 
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+    22 13 17 11  0
+    8  2 23  4 24
+    21  9 14 16  7
+    6 10  3 18  5
+    1 12 20 15 19
+
+    3 15  0  2 22
+    9 18 13 17  5
+    19  8  7 25 23
+    20 11 10 24  4
+    14 21 16 12  6
+
+    14 21 17 24  4
+    10 16 15  9 19
+    18  8 23 26 20
+    22 11 13  6  5
+     2  0 12  3  7";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = "\
-        7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-        22 13 17 11  0
-        8  2 23  4 24
-        21  9 14 16  7
-        6 10  3 18  5
-        1 12 20 15 19
-
-        3 15  0  2 22
-        9 18 13 17  5
-        19  8  7 25 23
-        20 11 10 24  4
-        14 21 16 12  6
-
-        14 21 17 24  4
-        10 16 15  9 19
-        18  8 23 26 20
-        22 11 13  6  5
-         2  0 12  3  7";
-
     #[test]
     fn part1_examples() {
         let mut bingo: Bingo = EXAMPLE.parse().unwrap();
@@ -0,0 +1,81 @@
+//! Day 6: Lanternfish
+use crate::{Answer, Example, Quizzer};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = Vec<u8>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        collect(input)
+    }
+
+    fn part1(&self, fishies: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(predict_fishies(fishies, 80).into())
+    }
+
+    fn part2(&self, fishies: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(predict_fishies(fishies, 256).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("5934"),
+            part2: Some("26984457539"),
+        }]
+    }
+}
+
+/// Collect the quiz input
+fn collect(input: &str) -> anyhow::Result<Vec<u8>> {
+    input
+        .split(',')
+        .map(|v| match v.parse() {
+            Ok(timer @ 0..=8) => Ok(timer),
+            Ok(timer) => Err(anyhow::anyhow!("fish timer {} is out of range", timer)),
+            Err(e) => Err(anyhow::anyhow!("parsing fish timer '{}' failed: {}", v, e)),
+        })
+        .collect()
+}
+
+/// Predict the number of fishes after n_days
+fn predict_fishies(fishies: &[u8], n_days: usize) -> usize {
+    let mut days = [0usize; 9];
+
+    for fish in fishies {
+        days[*fish as usize] += 1;
+    }
+
+    for i in 0..n_days {
+        let today = i % days.len();
+        days[(today + 7) % days.len()] += days[today];
+    }
+
+    days.iter().sum()
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "3,4,3,1,2";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        let fishies = collect(EXAMPLE).unwrap();
+
+        assert_eq!(predict_fishies(&fishies, 18), 26);
+        assert_eq!(predict_fishies(&fishies, 80), 5934);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            predict_fishies(&collect(EXAMPLE).unwrap(), 256),
+            26984457539
+        );
+    }
+}
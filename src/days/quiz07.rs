@@ -1,24 +1,41 @@
 //! Day 7: The Treachery of Whales
-use aoc21::Quizzer;
+use crate::{Answer, Example, Quizzer};
 
 /// Todays quiz implementation
 pub struct Quiz;
 
 impl Quizzer for Quiz {
-    fn part1(&self, input: &str) -> String {
-        calc_simplified_fuel_consumption(collect(input)).to_string()
+    type Parsed = Vec<i64>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        collect(input)
+    }
+
+    fn part1(&self, crabs: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(calc_simplified_fuel_consumption(crabs.clone()).into())
+    }
+
+    fn part2(&self, crabs: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(calc_fuel_consumption(crabs.clone()).into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        calc_fuel_consumption(collect(input)).to_string()
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("37"),
+            part2: Some("168"),
+        }]
     }
 }
 
 /// Collect the quiz input
-fn collect(input: &str) -> Vec<i64> {
+fn collect(input: &str) -> anyhow::Result<Vec<i64>> {
     input
         .split(',')
-        .map(|v| v.parse().expect("parsing failed"))
+        .map(|v| {
+            v.parse()
+                .map_err(|e| anyhow::anyhow!("parsing crab position '{}' failed: {}", v, e))
+        })
         .collect()
 }
 
@@ -55,19 +72,23 @@ fn calc_fuel_consumption(mut crabs: Vec<i64>) -> i64 {
         .unwrap_or_default()
 }
 
+/// The worked example of the quiz description
+const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
-
     #[test]
     fn part1_examples() {
-        assert_eq!(calc_simplified_fuel_consumption(collect(EXAMPLE)), 37);
+        assert_eq!(
+            calc_simplified_fuel_consumption(collect(EXAMPLE).unwrap()),
+            37
+        );
     }
 
     #[test]
     fn part2_examples() {
-        assert_eq!(calc_fuel_consumption(collect(EXAMPLE)), 168);
+        assert_eq!(calc_fuel_consumption(collect(EXAMPLE).unwrap()), 168);
     }
 }
@@ -0,0 +1,254 @@
+//! Day 8: Seven Segment Search
+use crate::{util::*, Answer, Example, Quizzer};
+use anyhow::Context;
+use std::collections::{BTreeSet, HashMap};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = Vec<Io>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        collected_with(input, parse)
+    }
+
+    fn part1(&self, ios: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(count_unique_digits(ios).into())
+    }
+
+    fn part2(&self, ios: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(sum_output_values(ios)?.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("26"),
+            part2: Some("61229"),
+        }]
+    }
+}
+
+/// Alias for the signal patterns and outputs of each line of the input
+pub type Io = (Vec<BTreeSet<u8>>, Vec<BTreeSet<u8>>);
+
+/// Alias for a [`HashMap`] of input lines and their respective 7-segment value
+type Signals = HashMap<BTreeSet<u8>, u8>;
+
+/// Parse the puzzle input
+fn parse(line: &str) -> anyhow::Result<Io> {
+    let (patterns, outputs) = line
+        .split_once('|')
+        .ok_or_else(|| anyhow::anyhow!("missing '|' separator"))?;
+
+    Ok((
+        patterns
+            .split_ascii_whitespace()
+            .map(to_set)
+            .collect::<anyhow::Result<_>>()?,
+        outputs
+            .split_ascii_whitespace()
+            .map(to_set)
+            .collect::<anyhow::Result<_>>()?,
+    ))
+}
+
+/// Count the number of digits in the outputs which have a unique number of signal lines
+fn count_unique_digits(ios: &[Io]) -> usize {
+    ios.iter()
+        .map(|(_, o)| {
+            o.iter()
+                .filter(|digit| matches!(digit.len(), 2 | 3 | 4 | 7))
+                .count()
+        })
+        .sum()
+}
+
+/// Descramble signal lines, decode the outputs and calculate the sum
+fn sum_output_values(ios: &[Io]) -> anyhow::Result<usize> {
+    ios.iter()
+        .enumerate()
+        .map(|(n, (p, o))| {
+            decode_patterns(p)
+                .and_then(|signals| decode_output(o, signals))
+                .with_context(|| format!("line {}", n + 1))
+        })
+        .sum()
+}
+
+/// Generates a [`BTreeSet`] from a &[`str`]
+///
+/// It is assumed that the input is ASCII.
+///
+/// # Errors
+/// A seven segment digit has between 2 and 7 active signal lines, anything else is an error.
+fn to_set(input: &str) -> anyhow::Result<BTreeSet<u8>> {
+    let set: BTreeSet<u8> = input.as_bytes().iter().copied().collect();
+
+    if (2..=7).contains(&set.len()) {
+        Ok(set)
+    } else {
+        Err(anyhow::anyhow!(
+            "'{}' does not have between 2 and 7 signal lines",
+            input
+        ))
+    }
+}
+
+/// Tries to decode a scrambled input line pattern with the help of the already decoded signal lines
+fn match_pattern(
+    pat: &BTreeSet<u8>,
+    decided: &HashMap<u8, BTreeSet<u8>>,
+) -> Option<(u8, BTreeSet<u8>)> {
+    match pat.len() {
+        // can be 2, 3 or 5
+        5 => {
+            if let Some(one) = decided.get(&1) {
+                if pat.is_superset(one) {
+                    return Some((3, pat.clone()));
+                } else if let Some(four) = decided.get(&4) {
+                    let unique = four - one;
+                    if pat.is_superset(&unique) {
+                        return Some((5, pat.clone()));
+                    } else {
+                        return Some((2, pat.clone()));
+                    }
+                }
+            }
+        }
+        // can be 0, 6 or 9
+        6 => {
+            if let Some(four) = decided.get(&4) {
+                if pat.is_superset(four) {
+                    return Some((9, pat.clone()));
+                } else if let Some(one) = decided.get(&1) {
+                    if pat.is_superset(one) {
+                        return Some((0, pat.clone()));
+                    } else {
+                        return Some((6, pat.clone()));
+                    }
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    None
+}
+
+/// Tries to decode all scrambled input signals
+///
+/// # Errors
+/// Returns an error if the patterns lack those needed to tell the others apart.
+fn decode_patterns(patterns: &[BTreeSet<u8>]) -> anyhow::Result<Signals> {
+    let mut decided = HashMap::new();
+    let mut undecided = patterns.to_vec();
+
+    while !undecided.is_empty() {
+        let before = undecided.len();
+
+        undecided.retain(|v| match v.len() {
+            n @ (2 | 3 | 4 | 7) => {
+                decided
+                    .entry(CANDIDATES_BY_NUM[n][0])
+                    .or_insert_with(|| v.clone());
+                false
+            }
+            5 | 6 => {
+                if let Some((val, pat)) = match_pattern(v, &decided) {
+                    decided.entry(val).or_insert(pat);
+                    false
+                } else {
+                    true
+                }
+            }
+            _ => unreachable!(),
+        });
+
+        if undecided.len() == before {
+            return Err(anyhow::anyhow!(
+                "unable to decode the patterns, {} remain undecided",
+                before
+            ));
+        }
+    }
+
+    Ok(decided.into_iter().map(|(k, v)| (v, k)).collect())
+}
+
+/// Decode the output signals with the decoded input signals
+///
+/// # Errors
+/// Returns an error for an output which is none of the patterns.
+fn decode_output(outputs: &[BTreeSet<u8>], signals: Signals) -> anyhow::Result<usize> {
+    outputs
+        .iter()
+        .zip([1000, 100, 10, 1])
+        .map(|(out, mul)| {
+            signals
+                .get(out)
+                .map(|&digit| digit as usize * mul)
+                .ok_or_else(|| {
+                    let out: String = out.iter().map(|&b| b as char).collect();
+                    anyhow::anyhow!("output '{}' is none of the patterns", out)
+                })
+        })
+        .sum()
+}
+
+/// List of candidates per number of active signal lines
+///
+/// ```txt
+/// 0: none
+/// 1: none
+/// 2: 1
+/// 3: 7
+/// 4: 4
+/// 5: 2, 3, 5
+/// 6: 0, 6, 9
+/// 7: 8
+/// ```
+const CANDIDATES_BY_NUM: [&[u8]; 8] = [&[], &[], &[1], &[7], &[4], &[2, 3, 5], &[0, 6, 9], &[8]];
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+    edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+    fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+    fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+    aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+    fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+    dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+    bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+    egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+    gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        let ios = collected_with(EXAMPLE, parse).unwrap();
+        assert_eq!(count_unique_digits(&ios), 26);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let ios = collected_with(EXAMPLE, parse).unwrap();
+        assert_eq!(sum_output_values(&ios).unwrap(), 61229);
+    }
+
+    #[test]
+    fn malformed_input() {
+        let err = collected_with("ab | ab\nabcdefgh | ab", parse).unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+
+        let ios = collected_with("abcde | abcde", parse).unwrap();
+        assert!(sum_output_values(&ios).is_err());
+
+        let ios = collected_with("ab | abc", parse).unwrap();
+        assert!(sum_output_values(&ios).is_err());
+    }
+}
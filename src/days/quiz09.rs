@@ -0,0 +1,160 @@
+//! Day 9: Smoke Basin
+use crate::visualize::{Frame, Visualize};
+use crate::{util::*, Answer, Example, Quizzer};
+use std::collections::HashSet;
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = Grid<u8>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(&self, values: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(risk_low_points(values).into())
+    }
+
+    fn part2(&self, values: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(three_largest_basins(values).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("15"),
+            part2: Some("1134"),
+        }]
+    }
+
+    fn grid_input(&self) -> bool {
+        true
+    }
+
+    fn visualizer(&self) -> Option<&dyn Visualize<Parsed = Self::Parsed>> {
+        Some(self)
+    }
+}
+
+impl Visualize for Quiz {
+    fn visualize(
+        &self,
+        values: &Self::Parsed,
+        part: usize,
+        frame: &mut dyn FnMut(Frame) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        // the heightmap stays dim, so the lowpoints and the basins stand out
+        let mut picture = Frame::from_grid(values, |&height| height * 16);
+
+        if part == 1 {
+            for pos in lowpoint_positions(values) {
+                picture.set(pos, 255);
+            }
+
+            return frame(picture);
+        }
+
+        frame(picture.clone())?;
+
+        let mut visited = HashSet::new();
+
+        for lowpoint in lowpoint_positions(values) {
+            basin_size(values, lowpoint, &mut visited);
+
+            for &pos in &visited {
+                if values[pos] != 9 {
+                    picture.set(pos, 255);
+                }
+            }
+
+            frame(picture.clone())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks whether a given point is lower than its neighbours
+fn is_lowpoint(values: &Grid<u8>, x: usize, y: usize) -> bool {
+    values
+        .neighbour_values4((x, y))
+        .all(|(_, &height)| height > values[(x, y)])
+}
+
+/// Generates an iterator over all lowpoints in the given [`Grid<u8>`]
+fn lowpoint_positions(values: &Grid<u8>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    values
+        .index_iter()
+        .filter(|(x, y)| is_lowpoint(values, *x, *y))
+}
+
+/// Calculates the risk of all the lowpoints
+fn risk_low_points(values: &Grid<u8>) -> u64 {
+    lowpoint_positions(values)
+        .map(|p| values[p] as u64 + 1)
+        .sum()
+}
+
+/// Calculates the size of a basin centered around the lowpoint at (x, y)
+fn basin_size(
+    values: &Grid<u8>,
+    (x, y): (usize, usize),
+    visited: &mut HashSet<(usize, usize)>,
+) -> u64 {
+    visited.insert((x, y));
+
+    if values[(x, y)] == 9 {
+        return 0;
+    }
+
+    let size: u64 = values
+        .neighbours4((x, y))
+        .filter_map(|p| {
+            if !visited.contains(&p) {
+                Some(basin_size(values, p, visited))
+            } else {
+                None
+            }
+        })
+        .sum();
+
+    size + 1
+}
+
+/// Finds the three largest basins and calculates the product of their sizes
+fn three_largest_basins(values: &Grid<u8>) -> u64 {
+    let mut visited = HashSet::new();
+    let mut basins: Vec<_> = lowpoint_positions(values)
+        .map(|p| basin_size(values, p, &mut visited))
+        .collect();
+
+    basins.sort_unstable();
+    basins.iter().rev().take(3).product()
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    2199943210
+    3987894921
+    9856789892
+    8767896789
+    9899965678";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        let values = Grid::parse_digits(EXAMPLE).unwrap();
+        assert_eq!(risk_low_points(&values), 15);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let values = Grid::parse_digits(EXAMPLE).unwrap();
+        assert_eq!(three_largest_basins(&values), 1134);
+    }
+}
@@ -0,0 +1,157 @@
+//! Day 10: Syntax Scoring
+use crate::{Answer, Example, Quizzer};
+use anyhow::Context;
+use State::*;
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = Vec<LineState>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        line_states(input)
+    }
+
+    fn part1(&self, states: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(lines_score(states).into())
+    }
+
+    fn part2(&self, states: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(lines_completion_score(states)?.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("26397"),
+            part2: Some("288957"),
+        }]
+    }
+}
+
+/// The state of a line together with its missing closing delimiters
+pub type LineState = (State, Vec<char>);
+
+/// The states in which a line can be in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum State {
+    /// The line is missing closing delimiters
+    Incomplete,
+    /// The line contains at least one unmatched closing delimiter
+    Corrupted(char),
+}
+
+/// Get the state and missing delimiters of a single line
+fn line_state(line: &str) -> anyhow::Result<LineState> {
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        match (c, stack.last()) {
+            ('(', _) => stack.push(')'),
+            ('[', _) => stack.push(']'),
+            ('{', _) => stack.push('}'),
+            ('<', _) => stack.push('>'),
+            (')', Some(')')) | (']', Some(']')) | ('}', Some('}')) | ('>', Some('>')) => {
+                stack.pop();
+            }
+            (')' | ']' | '}' | '>', _) => return Ok((Corrupted(c), stack)),
+            (c, _) => return Err(anyhow::anyhow!("unexpected character '{}'", c)),
+        }
+    }
+
+    Ok((Incomplete, stack))
+}
+
+/// Get the state and missing delimiters of the n-th (zero based) line of the input
+fn numbered_line_state((n, line): (usize, &str)) -> anyhow::Result<LineState> {
+    line_state(line.trim()).with_context(|| format!("line {}", n + 1))
+}
+
+/// Get the states of all lines of the input
+fn line_states(input: &str) -> anyhow::Result<Vec<LineState>> {
+    input.lines().enumerate().map(numbered_line_state).collect()
+}
+
+/// Score a line according to part 1
+fn lines_score(states: &[LineState]) -> u64 {
+    states
+        .iter()
+        .map(|(state, _)| match state {
+            Incomplete => 0,
+            Corrupted(')') => 3,
+            Corrupted(']') => 57,
+            Corrupted('}') => 1197,
+            Corrupted('>') => 25137,
+            Corrupted(_) => unreachable!(),
+        })
+        .sum()
+}
+
+/// Score a line according to part 2
+fn line_completion((state, stack): &LineState) -> u64 {
+    if let Corrupted(_) = state {
+        return 0;
+    }
+
+    stack.iter().rev().fold(0, |score, c| {
+        score * 5
+            + match c {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                '>' => 4,
+                _ => 0,
+            }
+    })
+}
+
+/// Get the middle score according to part 2
+fn lines_completion_score(states: &[LineState]) -> anyhow::Result<u64> {
+    let mut scores = Vec::new();
+
+    for state in states {
+        let score = line_completion(state);
+        if score > 0 {
+            scores.push(score);
+        }
+    }
+
+    scores.sort_unstable();
+
+    scores
+        .get(scores.len() / 2)
+        .copied()
+        .ok_or_else(|| anyhow::anyhow!("no incomplete lines in input"))
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    [({(<(())[]>[[{[]{<()<>>
+    [(()[<>])]({[<{<<[]>>(
+    {([(<{}[<>[]}>{[]{[(<()>
+    (((({<>}<{<{<>}{[]{[]{}
+    [[<[([]))<([[{}[[()]]]
+    [{[{({}]{}}([{[{{{}}([]
+    {<[[]]>}<{[{[{[]{()[[[]
+    [<(<(<(<{}))><([]([]()
+    <{([([[(<>()){}]>(<<{{
+    <{([{{}}[<[[[<>{}]]]>[]]";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(lines_score(&line_states(EXAMPLE).unwrap()), 26397);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            lines_completion_score(&line_states(EXAMPLE).unwrap()).unwrap(),
+            288957
+        );
+    }
+}
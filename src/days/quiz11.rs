@@ -0,0 +1,227 @@
+//! Day 11: Dumbo Octopus
+use crate::visualize::{Frame, Visualize};
+use crate::{util::*, Answer, Example, Quizzer};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+/// A field of octopuses
+pub type Octopuses = Grid<(u8, bool)>;
+
+impl Quizzer for Quiz {
+    type Parsed = Octopuses;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, octopuses: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(steps(&mut octopuses.clone(), 100).into())
+    }
+
+    fn part2(&self, octopuses: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(first_synchronized_flash(&mut octopuses.clone()).into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("1656"),
+            part2: Some("195"),
+        }]
+    }
+
+    fn grid_input(&self) -> bool {
+        true
+    }
+
+    fn visualizer(&self) -> Option<&dyn Visualize<Parsed = Self::Parsed>> {
+        Some(self)
+    }
+}
+
+impl Visualize for Quiz {
+    fn visualize(
+        &self,
+        octopuses: &Self::Parsed,
+        part: usize,
+        frame: &mut dyn FnMut(Frame) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        let mut octopuses = octopuses.clone();
+        // flashing octopuses light up, the others glow with their energy
+        let shade = |&(energy, flashed): &(u8, bool)| if flashed { 255 } else { energy * 20 };
+
+        frame(Frame::from_grid(&octopuses, shade))?;
+
+        for day in 1.. {
+            let flashes = light_step(&mut octopuses);
+            frame(Frame::from_grid(&octopuses, shade))?;
+
+            let done = match part {
+                1 => day == 100,
+                _ => flashes as usize == octopuses.len(),
+            };
+
+            if done {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse the puzzle input
+fn parse(input: &str) -> anyhow::Result<Octopuses> {
+    Ok(Grid::parse_digits(input)?.map(|&energy| (energy, false)))
+}
+
+/// Increase the energy of all octopuses by 1
+fn increase(octopuses: &mut Octopuses) {
+    for (energy, flashed) in octopuses.iter_mut() {
+        *flashed = false;
+        *energy += 1;
+    }
+}
+
+/// Flash all the octopuses
+fn flash(octopuses: &mut Octopuses) -> u64 {
+    let mut flashes = 0;
+
+    for pos in octopuses.index_iter() {
+        if let (10.., false) = octopuses[pos] {
+            octopuses[pos].1 = true;
+            flashes += 1;
+
+            for (_, (energy, _)) in octopuses.neighbour_values8_mut(pos) {
+                *energy += 1;
+            }
+        }
+    }
+
+    flashes
+}
+
+/// Reset all previously flashing octopuses to 0 energy
+fn reset(octopuses: &mut Octopuses) {
+    for (energy, ref flashed) in octopuses.iter_mut() {
+        if *flashed {
+            *energy = 0;
+        }
+    }
+}
+
+/// Step the octopuses by one day
+fn light_step(octopuses: &mut Octopuses) -> u64 {
+    increase(octopuses);
+
+    let mut sum_flashes = 0;
+    loop {
+        let flashes = flash(octopuses);
+        sum_flashes += flashes;
+
+        if flashes == 0 {
+            break;
+        }
+    }
+
+    reset(octopuses);
+    sum_flashes
+}
+
+/// Step the octopuses by n_days
+fn steps(octopuses: &mut Octopuses, n_days: usize) -> u64 {
+    (1..=n_days).map(|_| light_step(octopuses)).sum()
+}
+
+/// Find the first day where ALL octopuses flash
+fn first_synchronized_flash(octopuses: &mut Octopuses) -> usize {
+    for day in 1.. {
+        if light_step(octopuses) as usize == octopuses.len() {
+            return day;
+        }
+    }
+
+    0
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    5483143223
+    2745854711
+    5264556173
+    6141336146
+    6357385478
+    4167524645
+    2176841721
+    6882881134
+    4846848554
+    5283751526";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEPS: &[&str] = &[
+        "\
+        6594254334
+        3856965822
+        6375667284
+        7252447257
+        7468496589
+        5278635756
+        3287952832
+        7993992245
+        5957959665
+        6394862637",
+        "\
+        8807476555
+        5089087054
+        8597889608
+        8485769600
+        8700908800
+        6600088989
+        6800005943
+        0000007456
+        9000000876
+        8700006848",
+    ];
+
+    /// Draw the energy levels of the octopuses
+    fn energies(octopuses: &Octopuses) -> String {
+        octopuses
+            .render_with(|&(energy, _)| char::from_digit(u32::from(energy), 10).unwrap_or('+'))
+            .to_string()
+    }
+
+    #[test]
+    fn part1_examples() {
+        let mut octopuses = parse(EXAMPLE).unwrap();
+
+        assert_eq!(light_step(&mut octopuses), 0);
+        assert_eq!(energies(&octopuses), energies(&parse(STEPS[0]).unwrap()));
+
+        assert_eq!(light_step(&mut octopuses), 35);
+        assert_eq!(energies(&octopuses), energies(&parse(STEPS[1]).unwrap()));
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(first_synchronized_flash(&mut parse(EXAMPLE).unwrap()), 195);
+    }
+
+    #[test]
+    fn visualization() {
+        let mut frames = Vec::new();
+        Quiz.visualize(&parse(EXAMPLE).unwrap(), 2, &mut |frame| {
+            frames.push(frame);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(frames.len(), 196);
+        assert_eq!(frames[0].dim(), (10, 10));
+        assert_eq!(frames[0].get((0, 0)), Some(100));
+        assert!(frames[195].pixels().iter().all(|&shade| shade == 255));
+    }
+}
@@ -0,0 +1,196 @@
+//! Day 13: Transparent Origami
+use crate::util::Grid;
+use crate::{Answer, Example, Quizzer};
+use anyhow::Context;
+use std::collections::BTreeSet;
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    type Parsed = (Paper, Vec<Folding>);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, (paper, folds): &Self::Parsed) -> anyhow::Result<Answer> {
+        let first = folds
+            .get(..1)
+            .ok_or_else(|| anyhow::anyhow!("no folding instructions in input"))?;
+
+        Ok(paper.clone().fold_and_count(first).into())
+    }
+
+    fn part2(&self, (paper, folds): &Self::Parsed) -> anyhow::Result<Answer> {
+        let mut paper = paper.clone();
+        paper.fold_and_count(folds);
+        Ok(paper.bitmap()?.into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("17"),
+            part2: Some("#####\n#...#\n#...#\n#...#\n#####"),
+        }]
+    }
+}
+
+/// Where to fold the paper
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Folding {
+    /// Fold along the x axis
+    X(usize),
+    /// Fold along the y axis
+    Y(usize),
+}
+
+/// Parse a single dot location
+fn parse_dot(line: &str) -> anyhow::Result<(usize, usize)> {
+    let (x, y) = line
+        .split_once(',')
+        .ok_or_else(|| anyhow::anyhow!("can't split dot location"))?;
+
+    Ok((x.parse()?, y.parse()?))
+}
+
+/// Parse a single folding instruction
+fn parse_folding(line: &str) -> anyhow::Result<Folding> {
+    let line = line
+        .strip_prefix("fold along ")
+        .ok_or_else(|| anyhow::anyhow!("can't find fold instruction"))?;
+    let (direction, axis) = line
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("can't split fold instruction"))?;
+
+    match direction {
+        "x" => Ok(Folding::X(axis.parse()?)),
+        "y" => Ok(Folding::Y(axis.parse()?)),
+        _ => Err(anyhow::anyhow!("unknown fold direction '{}'", direction)),
+    }
+}
+
+/// Parse the puzzle input
+fn parse(input: &str) -> anyhow::Result<(Paper, Vec<Folding>)> {
+    let mut lines = input.lines().map(str::trim).enumerate();
+
+    let dots = lines
+        .by_ref()
+        .take_while(|(_, l)| !l.is_empty())
+        .map(|(n, l)| parse_dot(l).with_context(|| format!("line {}: '{}'", n + 1, l)))
+        .collect::<anyhow::Result<_>>()?;
+
+    let folds = lines
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(n, l)| parse_folding(l).with_context(|| format!("line {}: '{}'", n + 1, l)))
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok((Paper(dots), folds))
+}
+
+/// The transparent paper
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Paper(BTreeSet<(usize, usize)>);
+
+impl Paper {
+    /// Fold the paper according to the provided folding instructions
+    fn fold(&mut self, folds: &[Folding]) {
+        let mut folded = BTreeSet::new();
+
+        let do_fold = |pos, &line| {
+            if pos > line {
+                let pos = 2 * line - pos;
+                if pos < line {
+                    return Some(pos);
+                }
+            }
+            None
+        };
+
+        for fold in folds {
+            match fold {
+                Folding::X(col) => self
+                    .0
+                    .retain(|&(x, y)| do_fold(x, col).map(|x| folded.insert((x, y))).is_none()),
+                Folding::Y(row) => self
+                    .0
+                    .retain(|&(x, y)| do_fold(y, row).map(|y| folded.insert((x, y))).is_none()),
+            }
+
+            self.0.extend(&folded);
+            folded.clear();
+        }
+    }
+
+    /// Fold the paper according to the provided folding instructions and count the number of dots
+    fn fold_and_count(&mut self, folds: &[Folding]) -> usize {
+        self.fold(folds);
+        self.0.len()
+    }
+}
+
+impl Paper {
+    /// The dots of the paper as a bitmap, which reads as letters once it is folded
+    fn bitmap(&self) -> anyhow::Result<Grid<bool>> {
+        let width = self.0.iter().map(|&(x, _)| x + 1).max().unwrap_or_default();
+        let height = self.0.iter().map(|&(_, y)| y + 1).max().unwrap_or_default();
+
+        let dots = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|pos| self.0.contains(&pos))
+            .collect();
+
+        (dots, width).try_into()
+    }
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    6,10
+    0,14
+    9,10
+    0,3
+    10,4
+    4,11
+    6,0
+    6,12
+    4,1
+    0,13
+    10,12
+    3,4
+    3,0
+    8,4
+    1,10
+    2,14
+    8,10
+    9,0
+
+    fold along y=7
+    fold along x=5";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        let (mut paper, folds) = parse(EXAMPLE).unwrap();
+        assert_eq!(paper.fold_and_count(&folds), 16);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let (mut paper, folds) = parse(EXAMPLE).unwrap();
+        paper.fold_and_count(&folds);
+        assert_eq!(
+            Answer::from(paper.bitmap().unwrap()).to_string(),
+            "\
+#####
+#...#
+#...#
+#...#
+#####"
+        );
+    }
+}
@@ -1,25 +1,37 @@
 //! Day 14: Extended Polymerization
-use aoc21::Quizzer;
+use crate::{Answer, Example, Quizzer};
 use std::collections::{BTreeSet, HashMap};
 
 /// Todays quiz implementation
 pub struct Quiz;
 
 /// A counter for all pairs in a polymer
-type Polymer = HashMap<(u8, u8), i64>;
+pub type Polymer = HashMap<(u8, u8), i64>;
 
 /// A mapping of pairs to the respective inserted value
-type Mapping = HashMap<(u8, u8), u8>;
+pub type Mapping = HashMap<(u8, u8), u8>;
 
 impl Quizzer for Quiz {
-    fn part1(&self, input: &str) -> String {
-        let (mut poly, map) = parse(input).unwrap();
-        step_polymer(&mut poly, &map, 10).to_string()
+    type Parsed = (Polymer, Mapping);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        parse(input)
+    }
+
+    fn part1(&self, (poly, map): &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(step_polymer(&mut poly.clone(), map, 10).into())
+    }
+
+    fn part2(&self, (poly, map): &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(step_polymer(&mut poly.clone(), map, 40).into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let (mut poly, map) = parse(input).unwrap();
-        step_polymer(&mut poly, &map, 40).to_string()
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("1588"),
+            part2: Some("2188189693529"),
+        }]
     }
 }
 
@@ -36,16 +48,17 @@ fn parse(input: &str) -> Result<(Polymer, Mapping), anyhow::Error> {
         *poly_count.entry((pair[0], pair[1])).or_default() += 1;
     }
 
-    let mapping: Mapping = input
+    let mapping = input
+        .enumerate()
         .skip(1)
-        .filter_map(|l| l.split_once("->"))
-        .map(|(l, r)| {
-            let l = l.trim().as_bytes();
-            let r = r.trim().as_bytes();
-
-            ((l[0], l[1]), r[0])
-        })
-        .collect();
+        .filter_map(|(n, l)| l.split_once("->").map(|rule| (n, rule)))
+        .map(
+            |(n, (l, r))| match (l.trim().as_bytes(), r.trim().as_bytes()) {
+                (&[l0, l1], &[r]) => Ok(((l0, l1), r)),
+                _ => Err(anyhow::anyhow!("line {}: invalid insertion rule", n + 2)),
+            },
+        )
+        .collect::<anyhow::Result<Mapping>>()?;
 
     Ok((poly_count, mapping))
 }
@@ -79,7 +92,7 @@ fn step_polymer(poly: &mut Polymer, map: &Mapping, n_steps: usize) -> i64 {
         *letter_count.entry(k.1).or_default() += v;
     }
 
-    let letter_count: BTreeSet<_> = letter_count.into_iter().map(|(_, v)| (v + 1) / 2).collect();
+    let letter_count: BTreeSet<_> = letter_count.into_values().map(|v| (v + 1) / 2).collect();
 
     let min = letter_count.iter().copied().next().unwrap_or_default();
     let max = letter_count.iter().copied().next_back().unwrap_or_default();
@@ -87,30 +100,31 @@ fn step_polymer(poly: &mut Polymer, map: &Mapping, n_steps: usize) -> i64 {
     max - min
 }
 
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    NNCB
+
+    CH -> B
+    HH -> N
+    CB -> H
+    NH -> C
+    HB -> C
+    HC -> B
+    HN -> C
+    NN -> C
+    BH -> H
+    NC -> B
+    NB -> B
+    BN -> B
+    BB -> N
+    BC -> B
+    CC -> N
+    CN -> C";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = "\
-        NNCB
-
-        CH -> B
-        HH -> N
-        CB -> H
-        NH -> C
-        HB -> C
-        HC -> B
-        HN -> C
-        NN -> C
-        BH -> H
-        NC -> B
-        NB -> B
-        BN -> B
-        BB -> N
-        BC -> B
-        CC -> N
-        CN -> C";
-
     #[test]
     fn part1_examples() {
         let (mut polymer, map) = parse(EXAMPLE).unwrap();
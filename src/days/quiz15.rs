@@ -0,0 +1,181 @@
+//! Day 15: Chiton
+use crate::{util::*, Answer, Example, Quizzer};
+use std::collections::{BinaryHeap, HashMap};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {
+    /// The cavern and the full map, tiled five times in each direction
+    type Parsed = (Risks, Risks);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let risks = parse(input)?;
+        let tiled = tile(&risks, 5)?;
+
+        Ok((risks, tiled))
+    }
+
+    fn part1(&self, (risks, _): &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(cheapest_path(risks).unwrap_or_default().into())
+    }
+
+    fn part2(&self, (_, tiled): &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(cheapest_path(tiled).unwrap_or_default().into())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE,
+            part1: Some("40"),
+            part2: Some("315"),
+        }]
+    }
+
+    fn grid_input(&self) -> bool {
+        true
+    }
+}
+
+/// A risk level map of a cavern
+pub type Risks = Grid<u8>;
+
+/// A node for use in Dijkstra's algorithm
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Node {
+    /// The position on the grid
+    pos: (usize, usize),
+    /// The cost of this node
+    cost: u64,
+}
+
+impl Node {
+    /// Create a new node
+    fn new(pos: (usize, usize), cost: u64) -> Self {
+        Self { pos, cost }
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            // In case of a tie, compare positions - this is necessary to be consistent with `PartialEq` and `Ord`
+            .then_with(|| self.pos.cmp(&other.pos))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parse the input of todays quiz
+fn parse(input: &str) -> anyhow::Result<Risks> {
+    let risks = Risks::parse_digits(input)?;
+
+    if let Some((x, y)) = risks.index_iter().find(|&pos| risks[pos] == 0) {
+        return Err(anyhow::anyhow!(
+            "line {}: risk level 0 at column {} is not within 1 to 9",
+            y + 1,
+            x + 1
+        ));
+    }
+
+    Ok(risks)
+}
+
+/// Tile the cavern repeat times in each direction, the risks growing with each tile
+fn tile(risks: &Risks, repeat: usize) -> anyhow::Result<Risks> {
+    let (width, height) = risks.dim();
+    let mut tiled = Vec::with_capacity(risks.len() * repeat * repeat);
+
+    for y in 0..height * repeat {
+        for x in 0..width * repeat {
+            // each tile further right or down adds 1 to the risks, wrapping around from 9 to 1
+            let risk = risks[(x % width, y % height)] as usize + x / width + y / height;
+            tiled.push(((risk - 1) % 9 + 1) as u8);
+        }
+    }
+
+    (tiled, width * repeat).try_into()
+}
+
+/// Calculate the lowest-risk paths total risk with dijkstras algorithm
+fn cheapest_path(risks: &Risks) -> Option<u64> {
+    let dim = risks.dim();
+    let start = (0, 0);
+    let end = (dim.0 - 1, dim.1 - 1);
+    let mut heap = BinaryHeap::new();
+    let adjacent: HashMap<_, _> = risks
+        .index_iter()
+        .map(|pos| (pos, risks.neighbours4(pos).collect::<Vec<_>>()))
+        .collect();
+
+    let mut costs: Grid<u64> = (vec![u64::MAX; risks.len()], dim.0).try_into().unwrap();
+
+    costs[start] = 0;
+    heap.push(Node::new(start, 0));
+
+    while let Some(Node { pos, cost }) = heap.pop() {
+        // end found, return the total cost
+        if pos == end {
+            return Some(cost);
+        }
+
+        if cost > costs[pos] {
+            continue;
+        }
+
+        for &npos in &adjacent[&pos] {
+            let next = Node::new(npos, cost + risks[npos] as u64);
+
+            if next.cost < costs[npos] {
+                costs[npos] = next.cost;
+                heap.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "\
+    1163751742
+    1381373672
+    2136511328
+    3694931569
+    7463417111
+    1319128137
+    1359912421
+    3125421639
+    1293138521
+    2311944581";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(cheapest_path(&parse(EXAMPLE).unwrap()), Some(40));
+    }
+
+    #[test]
+    fn part2_examples() {
+        let risks = parse(EXAMPLE).unwrap();
+        assert_eq!(cheapest_path(&tile(&risks, 5).unwrap()), Some(315));
+    }
+
+    #[test]
+    fn zero_risk_is_rejected() {
+        let err = parse("1163\n1381\n2106").unwrap_err();
+        assert!(err.to_string().starts_with("line 3:"));
+
+        // like the other grid days, whitespace around the rows is ignored
+        assert!(parse("1163 \n1381").is_ok());
+    }
+}
@@ -1,25 +1,37 @@
 //! Day 16: Packet Decoder
-use aoc21::{util::*, Quizzer};
+use crate::{util::*, Answer, Example, Quizzer};
 
 /// Todays quiz implementation
 pub struct Quiz;
 
 impl Quizzer for Quiz {
-    fn part1(&self, input: &str) -> String {
-        parser::parse(&base16_decode(input.trim()).unwrap())
-            .unwrap()
-            .version_sum()
-            .to_string()
+    type Parsed = Packet;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        decode(input)
+    }
+
+    fn part1(&self, packet: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(packet.version_sum().into())
+    }
+
+    fn part2(&self, packet: &Self::Parsed) -> anyhow::Result<Answer> {
+        Ok(packet.evaluate().into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        parser::parse(&base16_decode(input.trim()).unwrap())
-            .unwrap()
-            .evaluate()
-            .to_string()
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
     }
 }
 
+/// Decode the hexadecimal puzzle input into its outermost packet
+fn decode(input: &str) -> anyhow::Result<Packet> {
+    let data = base16_decode(input.trim())?;
+
+    parser::parse(&data)
+        .map_err(|e| anyhow::anyhow!("parsing packet failed: {:?}", e.map(|e| e.code)))
+}
+
 /// The supported operators
 #[allow(clippy::missing_docs_in_private_items)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -65,7 +77,7 @@ pub(crate) enum TypeData {
 
 /// A BITS-Packet as transmitted by the elves
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub(crate) struct Packet {
+pub struct Packet {
     /// Packet version
     version: u8,
     /// Packet payload
@@ -243,12 +255,20 @@ pub(crate) mod parser {
                 subpackets,
             )),
             |(header, packets)| {
+                let op = header.type_id.into();
+
+                // comparisons are only defined for exactly two subpackets
+                let comparison = matches!(
+                    op,
+                    Operator::GreaterThan | Operator::LessThan | Operator::Equal
+                );
+                if comparison && packets.len() != 2 {
+                    return None;
+                }
+
                 Some(Packet {
                     version: header.version,
-                    data: TypeData::Operator {
-                        op: header.type_id.into(),
-                        packets,
-                    },
+                    data: TypeData::Operator { op, packets },
                 })
             },
         )(input)
@@ -270,43 +290,101 @@ pub(crate) mod parser {
     }
 }
 
+/// The worked examples of the quiz description, each covering only one of the parts
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "D2FE28",
+        part1: Some("6"),
+        part2: None,
+    },
+    Example {
+        input: "38006F45291200",
+        part1: Some("9"),
+        part2: None,
+    },
+    Example {
+        input: "EE00D40C823060",
+        part1: Some("14"),
+        part2: None,
+    },
+    Example {
+        input: "8A004A801A8002F478",
+        part1: Some("16"),
+        part2: None,
+    },
+    Example {
+        input: "620080001611562C8802118E34",
+        part1: Some("12"),
+        part2: None,
+    },
+    Example {
+        input: "C0015000016115A2E0802F182340",
+        part1: Some("23"),
+        part2: None,
+    },
+    Example {
+        input: "A0016C880162017C3686B18A3D4780",
+        part1: Some("31"),
+        part2: None,
+    },
+    Example {
+        input: "C200B40A82",
+        part1: None,
+        part2: Some("3"),
+    },
+    Example {
+        input: "04005AC33890",
+        part1: None,
+        part2: Some("54"),
+    },
+    Example {
+        input: "880086C3E88112",
+        part1: None,
+        part2: Some("7"),
+    },
+    Example {
+        input: "CE00C43D881120",
+        part1: None,
+        part2: Some("9"),
+    },
+    Example {
+        input: "D8005AC2A8F0",
+        part1: None,
+        part2: Some("1"),
+    },
+    Example {
+        input: "F600BC2D8F",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        input: "9C005AC2F8F0",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        input: "9C0141080250320F1802104A08",
+        part1: None,
+        part2: Some("1"),
+    },
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLES1: &[&str] = &[
-        "D2FE28",
-        "38006F45291200",
-        "EE00D40C823060",
-        "8A004A801A8002F478",
-        "620080001611562C8802118E34",
-        "C0015000016115A2E0802F182340",
-        "A0016C880162017C3686B18A3D4780",
-    ];
-
-    const EXAMPLES2: &[(&str, u64)] = &[
-        ("C200B40A82", 3),
-        ("04005AC33890", 54),
-        ("880086C3E88112", 7),
-        ("CE00C43D881120", 9),
-        ("D8005AC2A8F0", 1),
-        ("F600BC2D8F", 0),
-        ("9C005AC2F8F0", 0),
-        ("9C0141080250320F1802104A08", 1),
-    ];
-
     #[test]
     fn part1_examples() {
-        let parsed: Result<Vec<_>, _> = EXAMPLES1.iter().map(|x| base16_decode(x)).collect();
+        let examples: Vec<_> = EXAMPLES.iter().filter(|e| e.part1.is_some()).collect();
+        let parsed: Result<Vec<_>, _> = examples.iter().map(|e| base16_decode(e.input)).collect();
         let parsed = parsed.unwrap();
 
-        assert_eq!(parser::parse(&parsed[0]).unwrap().version_sum(), 6);
-        assert_eq!(parser::parse(&parsed[1]).unwrap().version_sum(), 9);
-        assert_eq!(parser::parse(&parsed[2]).unwrap().version_sum(), 14);
-        assert_eq!(parser::parse(&parsed[3]).unwrap().version_sum(), 16);
-        assert_eq!(parser::parse(&parsed[4]).unwrap().version_sum(), 12);
-        assert_eq!(parser::parse(&parsed[5]).unwrap().version_sum(), 23);
-        assert_eq!(parser::parse(&parsed[6]).unwrap().version_sum(), 31);
+        for (example, parsed) in examples.iter().zip(&parsed) {
+            assert_eq!(
+                parser::parse(parsed).unwrap().version_sum().to_string(),
+                example.part1.unwrap()
+            );
+        }
 
         assert_eq!(
             parser::parse(&parsed[0]),
@@ -521,18 +599,19 @@ mod tests {
 
     #[test]
     fn part2_examples() {
-        let parsed: Result<Vec<_>, _> = EXAMPLES2
-            .iter()
-            .map(|(packet, result)| (base16_decode(packet).map(|packet| (packet, *result))))
-            .collect();
-        let parsed = parsed.unwrap();
+        for example in EXAMPLES.iter().filter(|e| e.part2.is_some()) {
+            let packet = base16_decode(example.input).unwrap();
 
-        assert_eq!(parser::parse(&parsed[0].0).unwrap().evaluate(), parsed[0].1);
-        assert_eq!(parser::parse(&parsed[1].0).unwrap().evaluate(), parsed[1].1);
-        assert_eq!(parser::parse(&parsed[2].0).unwrap().evaluate(), parsed[2].1);
-        assert_eq!(parser::parse(&parsed[3].0).unwrap().evaluate(), parsed[3].1);
-        assert_eq!(parser::parse(&parsed[4].0).unwrap().evaluate(), parsed[4].1);
-        assert_eq!(parser::parse(&parsed[5].0).unwrap().evaluate(), parsed[5].1);
-        assert_eq!(parser::parse(&parsed[6].0).unwrap().evaluate(), parsed[6].1);
+            assert_eq!(
+                parser::parse(&packet).unwrap().evaluate().to_string(),
+                example.part2.unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn comparison_needs_two_packets() {
+        // a less than packet with a single literal subpacket
+        assert!(decode("1A004408").is_err());
     }
 }
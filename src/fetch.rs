@@ -0,0 +1,146 @@
+//! Downloading of quiz inputs into the input directory
+use crate::app::help;
+use crate::client::{self, Client};
+use anyhow::Context;
+use std::ffi::OsString;
+use std::fs;
+use std::io::stdout;
+use std::path::{Path, PathBuf};
+
+/// A description of the command line arguments of the `fetch` command
+#[derive(Debug)]
+struct Args {
+    /// Option to print the help output
+    help: bool,
+    /// Option to set the directory the inputs are cached in
+    input_dir: PathBuf,
+    /// The options of the client
+    client: client::Options,
+    /// The days to fetch the inputs of
+    days: Vec<usize>,
+}
+
+impl Args {
+    /// Generates [`Args`] with the help of the pico-args crate
+    fn try_from_vec(args: Vec<OsString>) -> anyhow::Result<Self> {
+        let mut args = pico_args::Arguments::from_vec(args);
+
+        let mut parsed = Self {
+            help: args.contains(["-h", "--help"]),
+            input_dir: args
+                .opt_value_from_str(["-d", "--input-dir"])?
+                .unwrap_or_else(|| PathBuf::from("inputs")),
+            client: client::Options::from_args(&mut args)?,
+            days: Vec::new(),
+        };
+
+        for day in args.finish() {
+            let day = day.to_string_lossy();
+            parsed.days.push(match day.parse() {
+                Ok(day @ 1..=25) => day,
+                _ => return Err(anyhow::anyhow!("'{}' is not a day of the advent", day)),
+            });
+        }
+
+        if parsed.days.is_empty() && !parsed.help {
+            return Err(anyhow::anyhow!("fetch requires at least one DAY"));
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Runs the `fetch` command with the arguments following it
+pub fn command(args: Vec<OsString>) -> anyhow::Result<bool> {
+    let args = Args::try_from_vec(args)?;
+
+    if args.help {
+        help(stdout());
+        return Ok(true);
+    }
+
+    let mut client = None;
+
+    for day in args.days {
+        if let Some(path) = cached(&args.input_dir, day) {
+            println!("Day {}: cached in {}", day, path.display());
+            continue;
+        }
+
+        let client = match &mut client {
+            Some(client) => client,
+            None => {
+                fs::create_dir_all(&args.input_dir).with_context(|| {
+                    format!(
+                        "creating input directory {} failed",
+                        args.input_dir.display()
+                    )
+                })?;
+
+                client.insert(args.client.client(args.input_dir.join(".last-request"))?)
+            }
+        };
+
+        let path = fetch(client, &args.input_dir, day)?;
+        println!("Day {}: fetched into {}", day, path.display());
+    }
+
+    Ok(true)
+}
+
+/// The cached input of a day, if there is one
+///
+/// Inputs are looked up like [`crate::input::Inputs`] does.
+fn cached(dir: &Path, day: usize) -> Option<PathBuf> {
+    [dir.join(day.to_string()), dir.join(format!("{}.txt", day))]
+        .into_iter()
+        .find(|path| path.is_file())
+}
+
+/// Download the input of a day into the directory
+///
+/// The input is written to a temporary file first, so an interrupted download never shows up as input.
+fn fetch(client: &Client, dir: &Path, day: usize) -> anyhow::Result<PathBuf> {
+    let input = client.input(day)?;
+
+    let path = dir.join(day.to_string());
+    let partial = dir.join(format!(".{}.partial", day));
+
+    fs::write(&partial, input).with_context(|| format!("writing {} failed", partial.display()))?;
+    fs::rename(&partial, &path).with_context(|| format!("writing {} failed", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+    use std::time::Duration;
+
+    #[test]
+    fn fetch_and_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc21-fetch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let (url, server) = stub::server("199\n200\n");
+        let client = Client::new(
+            &url,
+            String::from("token"),
+            Duration::ZERO,
+            dir.join(".last-request"),
+        );
+
+        assert_eq!(cached(&dir, 1), None);
+
+        let path = fetch(&client, &dir, 1).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "199\n200\n");
+        assert_eq!(cached(&dir, 1), Some(path));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2021/day/1/input HTTP/1.1\n"));
+        assert!(request.contains("\nCookie: session=token\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
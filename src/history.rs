@@ -0,0 +1,237 @@
+//! A history of the execution times of past runs
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The execution time of a single step of a quiz
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Step {
+    /// The day of the quiz
+    pub day: usize,
+    /// The step, `parse`, `part1` or `part2`
+    pub step: String,
+    /// The execution time in nanoseconds
+    pub duration_ns: u64,
+}
+
+/// The execution times of all steps of a single run
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Run {
+    /// The git commit the run was made on or, outside of a git repository, its timestamp
+    pub key: String,
+    /// When the run was made, in seconds since the unix epoch
+    pub timestamp: u64,
+    /// The steps which succeeded
+    pub steps: Vec<Step>,
+}
+
+impl Run {
+    /// Create a run made just now
+    pub fn new(steps: Vec<Step>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+
+        Self {
+            key: git_commit().unwrap_or_else(|| timestamp.to_string()),
+            timestamp,
+            steps,
+        }
+    }
+
+    /// The execution time of a step, if it succeeded in this run
+    fn duration_ns(&self, day: usize, step: &str) -> Option<u64> {
+        self.steps
+            .iter()
+            .find(|s| s.day == day && s.step == step)
+            .map(|s| s.duration_ns)
+    }
+}
+
+/// The short hash of the checked out git commit, if there is one
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    let commit = String::from_utf8(output.stdout).ok()?;
+    let commit = commit.trim();
+
+    if output.status.success() && !commit.is_empty() {
+        Some(commit.to_string())
+    } else {
+        None
+    }
+}
+
+/// All past runs, oldest first
+///
+/// The history file contains one JSON object per run and line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History(Vec<Run>);
+
+impl History {
+    /// Load the history from a file, which is empty if the file does not exist yet
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("reading history {} failed", path.display()))
+            }
+        };
+
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                serde_json::from_str(line).with_context(|| {
+                    format!("parsing history {} failed: line {}", path.display(), n + 1)
+                })
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(Self)
+    }
+
+    /// Append a run to the history file
+    pub fn append(path: &Path, run: &Run) -> anyhow::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening history {} failed", path.display()))?;
+
+        let mut line = serde_json::to_string(run)?;
+        line.push('\n');
+
+        file.write_all(line.as_bytes())
+            .with_context(|| format!("writing history {} failed", path.display()))
+    }
+
+    /// The latest run with the given key, or the latest run at all
+    ///
+    /// A key also matches the runs whose key it is a prefix of, e.g. the short hash of a commit.
+    pub fn baseline(&self, key: Option<&str>) -> Option<&Run> {
+        match key {
+            Some(key) => self.0.iter().rev().find(|run| run.key.starts_with(key)),
+            None => self.0.last(),
+        }
+    }
+}
+
+/// The changes in execution time between a baseline and the current run
+pub struct Comparison<'a> {
+    /// The run to compare against
+    pub baseline: &'a Run,
+    /// The current run
+    pub current: &'a Run,
+    /// The percentage by which a step may slow down before it counts as a regression
+    pub threshold: f64,
+}
+
+impl Comparison<'_> {
+    /// The steps which succeeded in both runs with their execution times and relative changes in percent
+    fn deltas(&self) -> impl Iterator<Item = (&Step, u64, f64)> {
+        self.current.steps.iter().filter_map(|step| {
+            let before = self.baseline.duration_ns(step.day, &step.step)?;
+            let change = (step.duration_ns as f64 / before.max(1) as f64 - 1.) * 100.;
+
+            Some((step, before, change))
+        })
+    }
+
+    /// The number of steps which slowed down by more than the threshold
+    pub fn regressions(&self) -> usize {
+        self.deltas()
+            .filter(|(_, _, change)| *change > self.threshold)
+            .count()
+    }
+}
+
+impl fmt::Display for Comparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Compared to {}:", self.baseline.key)?;
+
+        for (step, before, change) in self.deltas() {
+            write!(
+                f,
+                "quiz{:0>2}-{:<5} {:>9} us -> {:>9} us {:>+8.1} %",
+                step.day,
+                step.step,
+                before / 1000,
+                step.duration_ns / 1000,
+                change
+            )?;
+
+            if change > self.threshold {
+                write!(f, " SLOWER")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A run with the given key and execution times of the first parts of day 1 and 2
+    fn run(key: &str, durations: [u64; 2]) -> Run {
+        Run {
+            key: key.to_string(),
+            timestamp: 0,
+            steps: durations
+                .iter()
+                .enumerate()
+                .map(|(i, &duration_ns)| Step {
+                    day: i + 1,
+                    step: String::from("part1"),
+                    duration_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn baseline() {
+        let history = History(vec![
+            run("abc1234", [1, 1]),
+            run("def5678", [2, 2]),
+            run("abc1234", [3, 3]),
+        ]);
+
+        assert_eq!(history.baseline(None), Some(&run("abc1234", [3, 3])));
+        assert_eq!(history.baseline(Some("def")), Some(&run("def5678", [2, 2])));
+        assert_eq!(history.baseline(Some("123")), None);
+    }
+
+    #[test]
+    fn comparison() {
+        let baseline = run("abc1234", [100_000, 200_000]);
+        let current = run("def5678", [105_000, 300_000]);
+        let comparison = Comparison {
+            baseline: &baseline,
+            current: &current,
+            threshold: 10.,
+        };
+
+        assert_eq!(comparison.regressions(), 1);
+        assert_eq!(
+            comparison.to_string(),
+            "Compared to abc1234:
+quiz01-part1       100 us ->       105 us     +5.0 %
+quiz02-part1       200 us ->       300 us    +50.0 % SLOWER
+"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Loading of quiz inputs at runtime
+use anyhow::Context;
+use aoc21::days::Day;
+use std::borrow::Cow;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// The places quiz inputs are looked up in, in order of precedence
+///
+/// 1. An input file for a single quiz (`-` reads from stdin)
+/// 2. A directory containing files named `N` or `N.txt` for the quiz of day N
+/// 3. The input embedded into the binary (if built with the `embedded-inputs` feature)
+#[derive(Clone, Debug, Default)]
+pub struct Inputs {
+    /// The input file of a single quiz
+    pub file: Option<PathBuf>,
+    /// The directory containing the inputs of all quizzes
+    pub dir: Option<PathBuf>,
+}
+
+impl Inputs {
+    /// Load the input of the given quiz, [`normalize`]d
+    pub fn load(&self, entry: &Day) -> anyhow::Result<Cow<'static, str>> {
+        self.load_raw(entry).map(normalize)
+    }
+
+    /// Load the input of the given quiz as it is
+    fn load_raw(&self, entry: &Day) -> anyhow::Result<Cow<'static, str>> {
+        if let Some(file) = &self.file {
+            return read(file).map(Cow::Owned);
+        }
+
+        if let Some(dir) = &self.dir {
+            let candidates = [
+                dir.join(entry.day.to_string()),
+                dir.join(format!("{}.txt", entry.day)),
+            ];
+
+            if let Some(file) = candidates.iter().find(|path| path.is_file()) {
+                return read(file).map(Cow::Owned);
+            }
+        }
+
+        entry
+            .embedded_input
+            .map(Cow::Borrowed)
+            .ok_or_else(|| anyhow::anyhow!("no input found for day {}", entry.day))
+    }
+}
+
+/// Read an input file, where `-` denotes stdin
+fn read(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("reading input from stdin failed")?;
+
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("reading input {} failed", path.display()))
+    }
+}
+
+/// The byte order mark some editors put at the start of a file
+const BOM: char = '\u{feff}';
+
+/// Normalise an input, so quizzes do not have to care about how it was saved
+///
+/// Strips a byte order mark, converts CRLF line endings to LF and trims trailing blank lines, including the line
+/// ending of the last line. Inputs which need none of this are passed through without copying.
+pub fn normalize(input: Cow<'static, str>) -> Cow<'static, str> {
+    match input {
+        Cow::Borrowed(input) if !input.contains('\r') => {
+            Cow::Borrowed(trim_end(input.strip_prefix(BOM).unwrap_or(input)))
+        }
+        input => {
+            let input = input
+                .strip_prefix(BOM)
+                .unwrap_or(&input)
+                .replace("\r\n", "\n");
+            Cow::Owned(trim_end(&input).to_string())
+        }
+    }
+}
+
+/// Trim the trailing lines of an input which are empty or contain only whitespace
+fn trim_end(mut input: &str) -> &str {
+    loop {
+        let (rest, last) = input.rsplit_once('\n').unwrap_or(("", input));
+
+        if !last.trim().is_empty() || input.is_empty() {
+            return input;
+        }
+
+        input = rest;
+    }
+}
+
+/// A suspicious property of an input, which might keep a quiz from parsing it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Warning {
+    /// The input is empty
+    Empty,
+    /// A line contains a tab
+    Tab {
+        /// The number of the line, starting at 1
+        line: usize,
+    },
+    /// A line of a grid is not as long as the first one
+    LineLength {
+        /// The number of the line, starting at 1
+        line: usize,
+        /// The length of the first line
+        expected: usize,
+        /// The length of the line
+        found: usize,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the input is empty"),
+            Self::Tab { line } => write!(f, "line {} contains a tab", line),
+            Self::LineLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} is {} characters long, unlike the {} of the first line",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+/// Find the suspicious properties of a normalised input
+///
+/// The lines of a `grid` are expected to be of the same length. Only the first line of each kind of problem
+/// is reported.
+pub fn lint(input: &str, grid: bool) -> Vec<Warning> {
+    if input.trim().is_empty() {
+        return vec![Warning::Empty];
+    }
+
+    let lines = || input.lines().enumerate().map(|(n, line)| (n + 1, line));
+    let mut warnings = Vec::new();
+
+    if let Some((line, _)) = lines().find(|(_, line)| line.contains('\t')) {
+        warnings.push(Warning::Tab { line });
+    }
+
+    if grid {
+        let expected = input.lines().next().map_or(0, |line| line.chars().count());
+
+        if let Some((line, found)) = lines()
+            .map(|(n, line)| (n, line.chars().count()))
+            .find(|&(_, found)| found != expected)
+        {
+            warnings.push(Warning::LineLength {
+                line,
+                expected,
+                found,
+            });
+        }
+    }
+
+    warnings
+}
+
+/// Print the warnings about the input of a quiz to stderr
+pub fn warn(entry: &Day, input: &str) {
+    for warning in lint(input, entry.quiz.grid_input()) {
+        eprintln!("quiz{:0>2} input: {}", entry.day, warning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalization() {
+        assert_eq!(normalize(Cow::Borrowed("3,4,3,1,2\n")), "3,4,3,1,2");
+        assert_eq!(normalize(Cow::Borrowed("\u{feff}1\n2\n \n\n")), "1\n2");
+        assert_eq!(
+            normalize(Cow::Owned(String::from("1\r\n\r\n2\r\n\r\n"))),
+            "1\n\n2"
+        );
+        assert_eq!(normalize(Cow::Borrowed("  1\n")), "  1");
+        assert_eq!(normalize(Cow::Borrowed("\n\n")), "");
+        assert!(matches!(
+            normalize(Cow::Borrowed("1\n2\n")),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn linting() {
+        assert_eq!(lint("", false), [Warning::Empty]);
+        assert_eq!(lint("2199\n3987\n98", false), []);
+        assert_eq!(
+            lint("2199\n3987\n98", true),
+            [Warning::LineLength {
+                line: 3,
+                expected: 4,
+                found: 2
+            }]
+        );
+        assert_eq!(
+            lint("forward 5\ndown\t5", false),
+            [Warning::Tab { line: 2 }]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Isolation of quiz steps which may panic or never finish
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Why an isolated step did not return on its own
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Failure {
+    /// The step did not finish within the timeout
+    Timeout(Duration),
+    /// The step panicked with the given message
+    Panic(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Timeout(timeout) => write!(f, "timed out after {:?}", timeout),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Failure {}
+
+/// Parse a timeout given in (fractional) seconds
+pub fn parse_timeout(s: &str) -> anyhow::Result<Duration> {
+    let timeout = Duration::try_from_secs_f64(s.parse()?)?;
+
+    if timeout.is_zero() {
+        return Err(anyhow::anyhow!("the timeout must be positive"));
+    }
+
+    Ok(timeout)
+}
+
+/// Run a closure, turning a panic into a [`Failure::Panic`] error
+///
+/// With a timeout, the closure runs on a worker thread and a [`Failure::Timeout`] error is returned once the
+/// timeout has passed. The worker thread can not be stopped, it keeps running until it finishes on its own or
+/// the process exits.
+pub fn isolated<T, F>(timeout: Option<Duration>, func: F) -> anyhow::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> anyhow::Result<T> + Send + 'static,
+{
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return caught(func),
+    };
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        // the receiver is gone if the step timed out
+        let _ = tx.send(caught(func));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(Failure::Timeout(timeout).into()),
+        Err(RecvTimeoutError::Disconnected) => Err(anyhow::anyhow!("worker thread vanished")),
+    }
+}
+
+/// Run a closure and catch a panic
+fn caught<T, F>(func: F) -> anyhow::Result<T>
+where
+    F: FnOnce() -> anyhow::Result<T>,
+{
+    panic::catch_unwind(AssertUnwindSafe(func))
+        .unwrap_or_else(|payload| Err(Failure::Panic(panic_message(payload)).into()))
+}
+
+/// Extract the message of a panic
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or_else(
+            || String::from("unknown cause"),
+            |message| message.to_string(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isolation() {
+        let timeout = Some(Duration::from_millis(50));
+
+        assert_eq!(isolated(None, || Ok(1)).unwrap(), 1);
+        assert_eq!(isolated(timeout, || Ok(2)).unwrap(), 2);
+
+        let panicked = isolated::<(), _>(timeout, || panic!("at the {}", "disco"));
+        assert_eq!(
+            panicked.unwrap_err().downcast_ref(),
+            Some(&Failure::Panic(String::from("at the disco")))
+        );
+
+        let hung = isolated::<(), _>(timeout, || loop {
+            thread::sleep(Duration::from_millis(10))
+        });
+        assert_eq!(
+            hung.unwrap_err().downcast_ref(),
+            Some(&Failure::Timeout(Duration::from_millis(50)))
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        assert_eq!(parse_timeout("1.5").unwrap(), Duration::from_millis(1500));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+}
@@ -3,26 +3,175 @@
 #![deny(dead_code, unused_imports, unused_mut)]
 #![warn(missing_docs, clippy::missing_docs_in_private_items)]
 
+use std::any::Any;
+use visualize::{Frame, Visualize};
+
+pub mod answer;
+pub mod days;
+pub mod visualize;
+
+pub use answer::Answer;
+
 /// Trait to be implemented by all 25 quizzes of the Advent of Code
 ///
-/// Implementations of this trait should return the quizzes result (which is probably always numeric) as a String.
-pub trait Quizzer {
+/// The input is parsed once by [`Quizzer::parse`], the result is then passed to both parts.
+/// Implementations of this trait return the quizzes result as an [`Answer`], which is almost always a number.
+///
+/// # Errors
+/// Parsing returns an error if the input is malformed, the parts if the quiz can not be solved for it.
+/// Errors should carry enough context (e.g. the offending line) to find the problem in the input.
+///
+/// Quizzes may be run on multiple threads at once, so implementations have to be [`Send`] and [`Sync`].
+pub trait Quizzer: Send + Sync {
+    /// The parsed input, shared by both parts
+    type Parsed: Send + Sync + 'static;
+
+    /// Parse the puzzle input
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed>;
+
     /// The first part of a quiz
-    fn part1(&self, input: &str) -> String {
-        let _ = input;
-        String::new()
+    fn part1(&self, parsed: &Self::Parsed) -> anyhow::Result<Answer> {
+        let _ = parsed;
+        Ok(Answer::default())
     }
 
     /// The second part of a quiz
-    fn part2(&self, input: &str) -> String {
-        let _ = input;
-        String::new()
+    fn part2(&self, parsed: &Self::Parsed) -> anyhow::Result<Answer> {
+        let _ = parsed;
+        Ok(Answer::default())
+    }
+
+    /// The worked examples of the quiz description
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    /// Whether the input is a grid, i.e. all its lines are of the same length
+    fn grid_input(&self) -> bool {
+        false
+    }
+
+    /// The [`Visualize`] implementation of the quiz, if its simulation can be watched
+    fn visualizer(&self) -> Option<&dyn Visualize<Parsed = Self::Parsed>> {
+        None
+    }
+}
+
+/// A worked example of a quiz together with the answers it should produce
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Example {
+    /// The example input
+    pub input: &'static str,
+    /// The answer of the first part, if the example covers it
+    pub part1: Option<&'static str>,
+    /// The answer of the second part, if the example covers it
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    /// The expected answer of the given part, if the example covers it
+    pub fn expected(&self, part: usize) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+/// The type-erased parsed input of a quiz
+pub type AnyParsed = Box<dyn Any + Send + Sync>;
+
+/// An object-safe version of [`Quizzer`], implemented for all quizzes
+///
+/// Allows to keep quizzes with different [`Quizzer::Parsed`] types in a single collection.
+pub trait DynQuizzer: Send + Sync {
+    /// Parse the puzzle input, see [`Quizzer::parse`]
+    fn parse(&self, input: &str) -> anyhow::Result<AnyParsed>;
+
+    /// The first part of a quiz, see [`Quizzer::part1`]
+    ///
+    /// # Errors
+    /// Returns an error if the parsed input was not produced by this quiz.
+    fn part1(&self, parsed: &AnyParsed) -> anyhow::Result<Answer>;
+
+    /// The second part of a quiz, see [`Quizzer::part2`]
+    ///
+    /// # Errors
+    /// Returns an error if the parsed input was not produced by this quiz.
+    fn part2(&self, parsed: &AnyParsed) -> anyhow::Result<Answer>;
+
+    /// The worked examples of the quiz description, see [`Quizzer::examples`]
+    fn examples(&self) -> &'static [Example];
+
+    /// Whether the input is a grid, see [`Quizzer::grid_input`]
+    fn grid_input(&self) -> bool;
+
+    /// Whether the quiz implements [`Visualize`], see [`Quizzer::visualizer`]
+    fn can_visualize(&self) -> bool;
+
+    /// Run a part of the quiz frame by frame, see [`Visualize::visualize`]
+    ///
+    /// # Errors
+    /// Returns an error if the quiz does not implement [`Visualize`] or the parsed input was not produced by
+    /// this quiz.
+    fn visualize(
+        &self,
+        parsed: &AnyParsed,
+        part: usize,
+        frame: &mut dyn FnMut(Frame) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()>;
+}
+
+impl<Q: Quizzer> DynQuizzer for Q {
+    fn parse(&self, input: &str) -> anyhow::Result<AnyParsed> {
+        Ok(Box::new(Quizzer::parse(self, input)?))
+    }
+
+    fn part1(&self, parsed: &AnyParsed) -> anyhow::Result<Answer> {
+        Quizzer::part1(self, downcast::<Q>(parsed)?)
+    }
+
+    fn part2(&self, parsed: &AnyParsed) -> anyhow::Result<Answer> {
+        Quizzer::part2(self, downcast::<Q>(parsed)?)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        Quizzer::examples(self)
+    }
+
+    fn grid_input(&self) -> bool {
+        Quizzer::grid_input(self)
+    }
+
+    fn can_visualize(&self) -> bool {
+        self.visualizer().is_some()
     }
+
+    fn visualize(
+        &self,
+        parsed: &AnyParsed,
+        part: usize,
+        frame: &mut dyn FnMut(Frame) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        self.visualizer()
+            .ok_or_else(|| anyhow::anyhow!("the quiz can not be visualized"))?
+            .visualize(downcast::<Q>(parsed)?, part, frame)
+    }
+}
+
+/// Recover the parsed input of a quiz from its type-erased form
+fn downcast<Q: Quizzer>(parsed: &AnyParsed) -> anyhow::Result<&Q::Parsed> {
+    parsed
+        .downcast_ref()
+        .ok_or_else(|| anyhow::anyhow!("parsed input of a different quiz"))
 }
 
 /// A set of utility functions and types useful for handling Advent of Code quizzes
 pub mod util {
-    use std::fmt::Debug;
+    use anyhow::Context;
+    use std::collections::HashMap;
+    use std::fmt;
     use std::ops::{Deref, DerefMut, Index, IndexMut};
     use std::str::FromStr;
 
@@ -45,6 +194,11 @@ pub mod util {
 
             x < width && y < height
         }
+
+        /// The position moved by (dx, dy), if it lies on the grid
+        fn offset<T>(&self, grid: &Grid<T>, offset: (isize, isize)) -> Option<Self>
+        where
+            Self: Sized;
     }
 
     impl private::Sealed for (usize, usize) {}
@@ -54,6 +208,20 @@ pub mod util {
         fn pos<T>(&self, _grid: &Grid<T>) -> (usize, usize) {
             (self.0, self.1)
         }
+
+        #[inline]
+        fn offset<T>(&self, grid: &Grid<T>, (dx, dy): (isize, isize)) -> Option<Self> {
+            let pos = (
+                self.0.checked_add_signed(dx)?,
+                self.1.checked_add_signed(dy)?,
+            );
+
+            if pos.is_on_grid(grid) {
+                Some(pos)
+            } else {
+                None
+            }
+        }
     }
 
     /// A position on a [`Grid<T>`] which wraps around at the borders
@@ -73,8 +241,35 @@ pub mod util {
         fn is_on_grid<T>(&self, _grid: &Grid<T>) -> bool {
             true
         }
+
+        #[inline]
+        fn offset<T>(&self, grid: &Grid<T>, (dx, dy): (isize, isize)) -> Option<Self> {
+            let (width, height) = grid.dim();
+            let (x, y) = self.pos(grid);
+
+            // the offset is reduced first, so neither sum can overflow
+            Some(WrappingPos(
+                (x + dx.rem_euclid(width as isize) as usize) % width,
+                (y + dy.rem_euclid(height as isize) as usize) % height,
+            ))
+        }
     }
 
+    /// The offsets of the four orthogonal neighbours of a position
+    pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+    /// The offsets of the eight orthogonal and diagonal neighbours of a position
+    pub const NEIGHBOURS8: [(isize, isize); 8] = [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (-1, 0),
+        (1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ];
+
     /// A two-dimensional grid of values
     #[derive(Clone, Debug, PartialEq, Eq, Hash)]
     pub struct Grid<T> {
@@ -87,6 +282,72 @@ pub mod util {
     }
 
     impl<T> Grid<T> {
+        /// Parse a grid with one character per cell, decoding each byte with f
+        ///
+        /// Each line becomes a row of the grid as it is, whitespace included, so whitespace can be a cell.
+        ///
+        /// # Errors
+        /// Returns an error for an empty input, for the first row whose length differs from the first one and
+        /// for the first byte f fails on, each mentioning its line number.
+        pub fn parse_with<E, F>(input: &str, f: F) -> anyhow::Result<Self>
+        where
+            E: Into<anyhow::Error>,
+            F: FnMut(u8) -> Result<T, E>,
+        {
+            Self::parse_lines_with(input.lines(), f)
+        }
+
+        /// Parse a grid from its rows, see [`Grid::parse_with`]
+        fn parse_lines_with<'a, E, F>(
+            lines: impl Iterator<Item = &'a str>,
+            mut f: F,
+        ) -> anyhow::Result<Self>
+        where
+            E: Into<anyhow::Error>,
+            F: FnMut(u8) -> Result<T, E>,
+        {
+            let mut values = Vec::new();
+            let mut width = None;
+
+            for (n, line) in lines.enumerate() {
+                match width {
+                    None => width = Some(line.len()),
+                    Some(width) if width != line.len() => {
+                        return Err(anyhow::anyhow!(
+                            "line {}: expected {} cells, found {}",
+                            n + 1,
+                            width,
+                            line.len()
+                        ));
+                    }
+                    Some(_) => {}
+                }
+
+                for b in line.bytes() {
+                    values.push(f(b).map_err(Into::into).with_context(|| {
+                        format!("line {}: unable to parse '{}'", n + 1, b.escape_ascii())
+                    })?);
+                }
+            }
+
+            match width {
+                None | Some(0) => Err(anyhow::anyhow!("the grid is empty")),
+                Some(width) => (values, width).try_into(),
+            }
+        }
+
+        /// A grid of the same dimensions, holding the value f returns for each cell
+        pub fn map<U, F>(&self, f: F) -> Grid<U>
+        where
+            F: FnMut(&T) -> U,
+        {
+            Grid {
+                values: self.values.iter().map(f).collect(),
+                width: self.width,
+                height: self.height,
+            }
+        }
+
         /// The dimensions of the grid
         ///
         /// Returns (width, height) of the grid
@@ -111,7 +372,10 @@ pub mod util {
 
         /// A mutable iterator over a grid row
         pub fn row_iter_mut(&mut self, row: usize) -> impl Iterator<Item = &mut T> {
-            self.values.iter_mut().skip(row).step_by(self.width)
+            self.values
+                .iter_mut()
+                .skip(row * self.width)
+                .take(self.width)
         }
 
         /// A mutable iterator over a grid column
@@ -119,6 +383,91 @@ pub mod util {
             self.values.iter_mut().skip(col).step_by(self.width)
         }
 
+        /// A row of the grid
+        ///
+        /// # Panics
+        /// Panics if the row lies outside of the grid.
+        pub fn row(&self, y: usize) -> &[T] {
+            assert!(y < self.height, "row {} is outside of the grid", y);
+            &self.values[y * self.width..(y + 1) * self.width]
+        }
+
+        /// A mutable row of the grid
+        ///
+        /// # Panics
+        /// Panics if the row lies outside of the grid.
+        pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+            assert!(y < self.height, "row {} is outside of the grid", y);
+            &mut self.values[y * self.width..(y + 1) * self.width]
+        }
+
+        /// An iterator over the rows of the grid, from top to bottom
+        pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+            self.values.chunks_exact(self.width)
+        }
+
+        /// An iterator over the mutable rows of the grid, from top to bottom
+        pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+            self.values.chunks_exact_mut(self.width)
+        }
+
+        /// An iterator over the columns of the grid, from left to right, see [`Grid::col_iter`]
+        pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+            (0..self.width).map(move |x| self.col_iter(x))
+        }
+
+        /// Swap the values at two positions
+        ///
+        /// Unlike `swap` of the underlying slice, which takes offsets into the values, this takes positions.
+        ///
+        /// # Panics
+        /// Panics if either position lies outside of the grid.
+        pub fn swap_cells<P: GridPos>(&mut self, a: P, b: P) {
+            assert!(a.is_on_grid(self) && b.is_on_grid(self));
+
+            let (a, b) = (self.offset_of(&a), self.offset_of(&b));
+            self.values.swap(a, b);
+        }
+
+        /// A view of the rectangle of the given dimensions (width, height) whose top left corner is at pos
+        ///
+        /// Returns [`None`] if the rectangle does not fit on the grid.
+        pub fn sub_grid(&self, pos: (usize, usize), dim: (usize, usize)) -> Option<SubGrid<'_, T>> {
+            Some(SubGrid {
+                rect: Rect::new(self, pos, dim)?,
+                grid: self,
+            })
+        }
+
+        /// A mutable view of the rectangle of the given dimensions (width, height) whose top left corner is at pos
+        ///
+        /// Returns [`None`] if the rectangle does not fit on the grid.
+        pub fn sub_grid_mut(
+            &mut self,
+            pos: (usize, usize),
+            dim: (usize, usize),
+        ) -> Option<SubGridMut<'_, T>> {
+            Some(SubGridMut {
+                rect: Rect::new(self, pos, dim)?,
+                grid: self,
+            })
+        }
+
+        /// Render the grid as text, drawing each cell as the character f returns for it
+        ///
+        /// Each row of the grid is drawn as a line, the lines are joined by `\n`. The text can be parsed back with
+        /// [`Grid::parse_with`].
+        pub fn render_with<F>(&self, f: F) -> Rendered<'_, T, F>
+        where
+            F: Fn(&T) -> char,
+        {
+            Rendered {
+                grid: self,
+                cell: f,
+                overlay: HashMap::new(),
+            }
+        }
+
         /// An iterator over all positions of the grid
         pub fn index_iter(&self) -> GridIndexIter {
             GridIndexIter {
@@ -126,6 +475,438 @@ pub mod util {
                 current: (0, 0),
             }
         }
+
+        /// An iterator over the positions at the given offsets from a position
+        ///
+        /// Positions off the grid are skipped, unless `pos` is a [`WrappingPos`], whose neighbours wrap around
+        /// at the borders.
+        pub fn neighbours<'a, P>(
+            &'a self,
+            pos: P,
+            offsets: &'a [(isize, isize)],
+        ) -> impl Iterator<Item = P> + 'a
+        where
+            P: GridPos + 'a,
+        {
+            offsets
+                .iter()
+                .filter_map(move |&offset| pos.offset(self, offset))
+        }
+
+        /// An iterator over the positions of the four orthogonal neighbours of a position, see [`Grid::neighbours`]
+        pub fn neighbours4<'a, P>(&'a self, pos: P) -> impl Iterator<Item = P> + 'a
+        where
+            P: GridPos + 'a,
+        {
+            self.neighbours(pos, &NEIGHBOURS4)
+        }
+
+        /// An iterator over the positions of all eight neighbours of a position, see [`Grid::neighbours`]
+        pub fn neighbours8<'a, P>(&'a self, pos: P) -> impl Iterator<Item = P> + 'a
+        where
+            P: GridPos + 'a,
+        {
+            self.neighbours(pos, &NEIGHBOURS8)
+        }
+
+        /// An iterator over the neighbours at the given offsets from a position together with their values
+        ///
+        /// See [`Grid::neighbours`] for which neighbours are yielded.
+        pub fn neighbour_values<'a, P>(
+            &'a self,
+            pos: P,
+            offsets: &'a [(isize, isize)],
+        ) -> impl Iterator<Item = (P, &'a T)> + 'a
+        where
+            P: GridPos + 'a,
+        {
+            self.neighbours(pos, offsets).map(move |neighbour| {
+                let value = &self.values[self.offset_of(&neighbour)];
+                (neighbour, value)
+            })
+        }
+
+        /// An iterator over the four orthogonal neighbours of a position together with their values
+        pub fn neighbour_values4<'a, P>(&'a self, pos: P) -> impl Iterator<Item = (P, &'a T)> + 'a
+        where
+            P: GridPos + 'a,
+        {
+            self.neighbour_values(pos, &NEIGHBOURS4)
+        }
+
+        /// An iterator over all eight neighbours of a position together with their values
+        pub fn neighbour_values8<'a, P>(&'a self, pos: P) -> impl Iterator<Item = (P, &'a T)> + 'a
+        where
+            P: GridPos + 'a,
+        {
+            self.neighbour_values(pos, &NEIGHBOURS8)
+        }
+
+        /// An iterator over the neighbours at the given offsets from a position together with their mutable values
+        ///
+        /// See [`Grid::neighbours`] for which neighbours are yielded. The neighbours are yielded row by row, a
+        /// neighbour which several offsets wrap around to only once.
+        pub fn neighbour_values_mut<P>(
+            &mut self,
+            pos: P,
+            offsets: &[(isize, isize)],
+        ) -> impl Iterator<Item = (P, &mut T)>
+        where
+            P: GridPos,
+        {
+            let mut neighbours: Vec<_> = self
+                .neighbours(pos, offsets)
+                .map(|neighbour| (self.offset_of(&neighbour), neighbour))
+                .collect();
+            neighbours.sort_unstable_by_key(|&(offset, _)| offset);
+            neighbours.dedup_by_key(|&mut (offset, _)| offset);
+
+            // hand out disjoint parts of the values, one after another
+            let mut rest = self.values.as_mut_slice();
+            let mut start = 0;
+
+            neighbours.into_iter().map(move |(offset, neighbour)| {
+                let (value, tail) = std::mem::take(&mut rest)[offset - start..]
+                    .split_first_mut()
+                    .expect("neighbours lie on the grid");
+                rest = tail;
+                start = offset + 1;
+
+                (neighbour, value)
+            })
+        }
+
+        /// An iterator over the four orthogonal neighbours of a position together with their mutable values
+        pub fn neighbour_values4_mut<P>(&mut self, pos: P) -> impl Iterator<Item = (P, &mut T)>
+        where
+            P: GridPos,
+        {
+            self.neighbour_values_mut(pos, &NEIGHBOURS4)
+        }
+
+        /// An iterator over all eight neighbours of a position together with their mutable values
+        pub fn neighbour_values8_mut<P>(&mut self, pos: P) -> impl Iterator<Item = (P, &mut T)>
+        where
+            P: GridPos,
+        {
+            self.neighbour_values_mut(pos, &NEIGHBOURS8)
+        }
+
+        /// The offset of a position into the underlying values
+        #[inline]
+        fn offset_of<P: GridPos>(&self, pos: &P) -> usize {
+            let (x, y) = pos.pos(self);
+            x + y * self.width
+        }
+    }
+
+    impl Grid<u8> {
+        /// Parse a grid of single digits, e.g. a heightmap
+        ///
+        /// Unlike [`Grid::parse_with`], the lines are trimmed, as whitespace can not be a digit.
+        ///
+        /// # Errors
+        /// See [`Grid::parse_with`], any byte which is not a digit is an error.
+        pub fn parse_digits(input: &str) -> anyhow::Result<Self> {
+            Self::parse_lines_with(input.lines().map(str::trim), digit)
+        }
+    }
+
+    impl Grid<char> {
+        /// Parse a grid of ASCII characters, e.g. a map drawn with `#` and `.`
+        ///
+        /// Like [`Grid::parse_with`], whitespace is kept as cells.
+        ///
+        /// # Errors
+        /// See [`Grid::parse_with`], any byte which is not ASCII is an error.
+        pub fn parse_chars(input: &str) -> anyhow::Result<Self> {
+            Self::parse_with(input, |b| {
+                if b.is_ascii() {
+                    Ok(b as char)
+                } else {
+                    Err(anyhow::anyhow!("not an ASCII character"))
+                }
+            })
+        }
+    }
+
+    /// A rectangle on a grid, the part of it a [`SubGrid`] or [`SubGridMut`] covers
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    struct Rect {
+        /// The position of the top left corner on the grid
+        origin: (usize, usize),
+        /// The width of the rectangle
+        width: usize,
+        /// The height of the rectangle
+        height: usize,
+        /// The width of the underlying grid
+        stride: usize,
+    }
+
+    impl Rect {
+        /// The rectangle at pos with the given dimensions, if it fits on the grid
+        fn new<T>(
+            grid: &Grid<T>,
+            pos: (usize, usize),
+            (width, height): (usize, usize),
+        ) -> Option<Self> {
+            let fits = pos.0.checked_add(width)? <= grid.width
+                && pos.1.checked_add(height)? <= grid.height;
+
+            fits.then_some(Self {
+                origin: pos,
+                width,
+                height,
+                stride: grid.width,
+            })
+        }
+
+        /// The offset into the grid values of a position relative to the top left corner
+        fn offset(&self, (x, y): (usize, usize)) -> Option<usize> {
+            if x < self.width && y < self.height {
+                Some(self.origin.0 + x + (self.origin.1 + y) * self.stride)
+            } else {
+                None
+            }
+        }
+
+        /// The range of the grid values which make up a row of the rectangle
+        ///
+        /// # Panics
+        /// Panics if the row lies outside of the rectangle.
+        fn row(&self, y: usize) -> std::ops::Range<usize> {
+            assert!(y < self.height, "row {} is outside of the sub-grid", y);
+
+            let start = self.origin.0 + (self.origin.1 + y) * self.stride;
+            start..start + self.width
+        }
+    }
+
+    /// A rectangular part of a [`Grid<T>`], indexed by positions relative to its top left corner
+    ///
+    /// Created by [`Grid::sub_grid`].
+    #[derive(Clone, Copy, Debug)]
+    pub struct SubGrid<'a, T> {
+        /// The grid this is a part of
+        grid: &'a Grid<T>,
+        /// The part of the grid covered
+        rect: Rect,
+    }
+
+    impl<'a, T> SubGrid<'a, T> {
+        /// The dimensions (width, height) of the sub-grid
+        pub fn dim(&self) -> (usize, usize) {
+            (self.rect.width, self.rect.height)
+        }
+
+        /// Whether a position lies within the sub-grid
+        pub fn contains(&self, pos: (usize, usize)) -> bool {
+            self.rect.offset(pos).is_some()
+        }
+
+        /// The value at a position, if it lies within the sub-grid
+        pub fn get(&self, pos: (usize, usize)) -> Option<&'a T> {
+            self.rect
+                .offset(pos)
+                .map(|offset| &self.grid.values[offset])
+        }
+
+        /// A row of the sub-grid
+        ///
+        /// # Panics
+        /// Panics if the row lies outside of the sub-grid.
+        pub fn row(&self, y: usize) -> &'a [T] {
+            &self.grid.values[self.rect.row(y)]
+        }
+
+        /// An iterator over the rows of the sub-grid, from top to bottom
+        pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+            (0..self.rect.height).map(move |y| self.row(y))
+        }
+
+        /// An iterator over all positions of the sub-grid
+        pub fn index_iter(&self) -> GridIndexIter {
+            GridIndexIter {
+                dim: self.dim(),
+                current: (0, 0),
+            }
+        }
+    }
+
+    impl<T> Index<(usize, usize)> for SubGrid<'_, T> {
+        type Output = T;
+
+        #[inline]
+        fn index(&self, pos: (usize, usize)) -> &Self::Output {
+            self.get(pos).expect("position is outside of the sub-grid")
+        }
+    }
+
+    /// A mutable rectangular part of a [`Grid<T>`], indexed by positions relative to its top left corner
+    ///
+    /// Created by [`Grid::sub_grid_mut`].
+    #[derive(Debug)]
+    pub struct SubGridMut<'a, T> {
+        /// The grid this is a part of
+        grid: &'a mut Grid<T>,
+        /// The part of the grid covered
+        rect: Rect,
+    }
+
+    impl<T> SubGridMut<'_, T> {
+        /// The dimensions (width, height) of the sub-grid
+        pub fn dim(&self) -> (usize, usize) {
+            (self.rect.width, self.rect.height)
+        }
+
+        /// Whether a position lies within the sub-grid
+        pub fn contains(&self, pos: (usize, usize)) -> bool {
+            self.rect.offset(pos).is_some()
+        }
+
+        /// The value at a position, if it lies within the sub-grid
+        pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+            self.rect
+                .offset(pos)
+                .map(|offset| &self.grid.values[offset])
+        }
+
+        /// The mutable value at a position, if it lies within the sub-grid
+        pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+            self.rect
+                .offset(pos)
+                .map(|offset| &mut self.grid.values[offset])
+        }
+
+        /// A row of the sub-grid
+        ///
+        /// # Panics
+        /// Panics if the row lies outside of the sub-grid.
+        pub fn row(&self, y: usize) -> &[T] {
+            &self.grid.values[self.rect.row(y)]
+        }
+
+        /// A mutable row of the sub-grid
+        ///
+        /// # Panics
+        /// Panics if the row lies outside of the sub-grid.
+        pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+            &mut self.grid.values[self.rect.row(y)]
+        }
+
+        /// An iterator over the rows of the sub-grid, from top to bottom
+        pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+            (0..self.rect.height).map(move |y| self.row(y))
+        }
+
+        /// An iterator over all positions of the sub-grid
+        pub fn index_iter(&self) -> GridIndexIter {
+            GridIndexIter {
+                dim: self.dim(),
+                current: (0, 0),
+            }
+        }
+
+        /// A read-only view of the same part of the grid
+        pub fn as_sub_grid(&self) -> SubGrid<'_, T> {
+            SubGrid {
+                grid: self.grid,
+                rect: self.rect,
+            }
+        }
+    }
+
+    impl<T> Index<(usize, usize)> for SubGridMut<'_, T> {
+        type Output = T;
+
+        #[inline]
+        fn index(&self, pos: (usize, usize)) -> &Self::Output {
+            self.get(pos).expect("position is outside of the sub-grid")
+        }
+    }
+
+    impl<T> IndexMut<(usize, usize)> for SubGridMut<'_, T> {
+        #[inline]
+        fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+            self.get_mut(pos)
+                .expect("position is outside of the sub-grid")
+        }
+    }
+
+    /// A grid rendered as text, see [`Grid::render_with`]
+    pub struct Rendered<'a, T, F> {
+        /// The rendered grid
+        grid: &'a Grid<T>,
+        /// Draws a single cell
+        cell: F,
+        /// Cells which are drawn with a marker instead of their value
+        overlay: HashMap<(usize, usize), char>,
+    }
+
+    impl<T, F> Rendered<'_, T, F> {
+        /// Draw the given cells with a marker instead of their value, e.g. to show a path through the grid
+        ///
+        /// Cells off the grid are ignored. Later highlights are drawn over earlier ones.
+        pub fn highlight<P, I>(mut self, cells: I, marker: char) -> Self
+        where
+            P: GridPos,
+            I: IntoIterator<Item = P>,
+        {
+            for cell in cells {
+                if cell.is_on_grid(self.grid) {
+                    self.overlay.insert(cell.pos(self.grid), marker);
+                }
+            }
+
+            self
+        }
+    }
+
+    impl<T, F> fmt::Display for Rendered<'_, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let (width, height) = self.grid.dim();
+
+            for y in 0..height {
+                if y > 0 {
+                    f.write_str("\n")?;
+                }
+
+                for x in 0..width {
+                    let c = match self.overlay.get(&(x, y)) {
+                        Some(&marker) => marker,
+                        None => (self.cell)(&self.grid[(x, y)]),
+                    };
+
+                    write!(f, "{}", c)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Draws a grid of digits as parsed by [`Grid::parse_digits`]
+    ///
+    /// Only grids whose values are all single digits round-trip through [`Grid::parse_digits`]. Larger values
+    /// are drawn as `+`, which [`Grid::parse_digits`] rejects, so they are never read back wrongly. Use
+    /// [`Grid::render_with`] and [`Grid::parse_with`] with a wider alphabet for them.
+    impl fmt::Display for Grid<u8> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.render_with(|&v| char::from_digit(u32::from(v), 10).unwrap_or('+'))
+                .fmt(f)
+        }
+    }
+
+    /// Draws a grid of characters as parsed by [`Grid::parse_chars`]
+    ///
+    /// Grids of ASCII characters, whitespace included, round-trip through [`Grid::parse_chars`].
+    impl fmt::Display for Grid<char> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.render_with(|&c| c).fmt(f)
+        }
     }
 
     /// An iterator over the indices of a grid
@@ -258,40 +1039,44 @@ pub mod util {
 
     /// Parses a newline separated input into an [`Iterator`] over T's
     ///
-    /// # Panics
-    /// Panics if the lines cannot be parsed.
-    pub fn parsed<T>(input: &str) -> impl Clone + Iterator<Item = T> + '_
+    /// # Errors
+    /// Each line which cannot be parsed yields an error mentioning its line number.
+    pub fn parsed<T>(input: &str) -> impl Clone + Iterator<Item = anyhow::Result<T>> + '_
     where
         T: FromStr,
-        T::Err: Debug,
+        T::Err: Into<anyhow::Error>,
     {
-        input
-            .lines()
-            .map(|l| l.trim().parse::<T>().expect("unable to parse AOC input"))
+        parsed_with(input, |l| l.parse::<T>())
     }
 
     /// Parses a newline separated input into an [`Iterator`] over T's
     ///
-    /// # Panics
-    /// Panics if the lines cannot be parsed.
-    pub fn parsed_with<'input, F, T>(
+    /// # Errors
+    /// Each line for which f fails yields an error mentioning its line number.
+    pub fn parsed_with<'input, E, F, T>(
         input: &'input str,
         f: F,
-    ) -> impl Clone + Iterator<Item = T> + 'input
+    ) -> impl Clone + Iterator<Item = anyhow::Result<T>> + 'input
     where
-        F: 'input + Clone + Fn(&'input str) -> T,
+        E: Into<anyhow::Error>,
+        F: 'input + Clone + Fn(&'input str) -> Result<T, E>,
     {
-        input.lines().map(str::trim).map(f)
+        input.lines().enumerate().map(move |(n, line)| {
+            let line = line.trim();
+            f(line)
+                .map_err(Into::into)
+                .with_context(|| format!("line {}: unable to parse '{}'", n + 1, line))
+        })
     }
 
     /// Parses a newline separated input into a [`Vec<T>`]
     ///
-    /// # Panics
-    /// Panics if the lines cannot be parsed.
-    pub fn collected<T>(input: &str) -> Vec<T>
+    /// # Errors
+    /// Returns the first line which cannot be parsed, if any.
+    pub fn collected<T>(input: &str) -> anyhow::Result<Vec<T>>
     where
         T: FromStr,
-        T::Err: Debug,
+        T::Err: Into<anyhow::Error>,
     {
         parsed(input).collect()
     }
@@ -300,19 +1085,29 @@ pub mod util {
     ///
     /// # Errors
     /// If f can error, this may return the first occurence of the error, if any.
-    pub fn collected_with<E, F, T>(input: &str, f: F) -> Result<Vec<T>, E>
+    pub fn collected_with<'input, E, F, T>(input: &'input str, f: F) -> anyhow::Result<Vec<T>>
     where
-        F: Clone + Fn(&str) -> Result<T, E>,
+        E: Into<anyhow::Error>,
+        F: 'input + Clone + Fn(&'input str) -> Result<T, E>,
     {
         parsed_with(input, f).collect()
     }
 
+    /// Decodes a single ASCII digit, e.g. a cell of a grid parsed by [`Grid::parse_with`]
+    pub fn digit(b: u8) -> anyhow::Result<u8> {
+        if b.is_ascii_digit() {
+            Ok(b - b'0')
+        } else {
+            Err(anyhow::anyhow!("'{}' is not a digit", b.escape_ascii()))
+        }
+    }
+
     /// Parses a base16 string into a [`Vec<u8>`]
     pub fn base16_decode(input: &str) -> anyhow::Result<Vec<u8>> {
         /// Base 16 decoding map
         const MAP: &[u8] = b"0123456789ABCDEF";
 
-        if input.len() % 2 != 0 {
+        if !input.len().is_multiple_of(2) {
             return Err(anyhow::anyhow!("input length is not a multiple of 2"));
         }
 
@@ -339,6 +1134,32 @@ pub mod util {
 mod tests {
     use super::*;
 
+    /// Sums up numbers, one per line
+    struct Sum;
+
+    impl Quizzer for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+            util::collected(input)
+        }
+
+        fn part1(&self, parsed: &Self::Parsed) -> anyhow::Result<Answer> {
+            Ok(parsed.iter().sum::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn dyn_quizzer() {
+        let quiz: &dyn DynQuizzer = &Sum;
+        let parsed = quiz.parse("1\n2\n3").unwrap();
+
+        assert_eq!(quiz.part1(&parsed).unwrap(), Answer::Int(6));
+        assert!(quiz.part2(&parsed).unwrap().is_empty());
+        assert!(quiz.part1(&(Box::new(6u32) as AnyParsed)).is_err());
+        assert!(quiz.parse("1\nx").is_err());
+    }
+
     #[test]
     fn grid_index_iter() {
         let grid: util::Grid<u8> = (vec![0; 16], 4).try_into().expect("can't construct grid");
@@ -365,6 +1186,229 @@ mod tests {
         );
     }
 
+    #[test]
+    fn grid_neighbours() {
+        use util::{Grid, WrappingPos};
+
+        let mut grid: Grid<u8> = ((0..12).collect::<Vec<_>>(), 4).try_into().unwrap();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((3, 2)).collect::<Vec<_>>(),
+            [(2, 1), (3, 1), (2, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbour_values4((1, 1))
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            [1, 4, 6, 9]
+        );
+        assert_eq!(
+            grid.neighbours4(WrappingPos(0, 0)).collect::<Vec<_>>(),
+            [
+                WrappingPos(0, 2),
+                WrappingPos(3, 0),
+                WrappingPos(1, 0),
+                WrappingPos(0, 1)
+            ]
+        );
+
+        for (_, value) in grid.neighbour_values8_mut((0, 0)) {
+            *value += 100;
+        }
+        assert_eq!(grid[(1, 0)], 101);
+        assert_eq!(grid[(0, 1)], 104);
+        assert_eq!(grid[(1, 1)], 105);
+        assert_eq!(grid[(0, 0)], 0);
+
+        // on a single row wrapping grid, left and right are the same cell, and so are up, down and the cell itself
+        let mut narrow: Grid<u8> = (vec![0; 2], 2).try_into().unwrap();
+        for (_, value) in narrow.neighbour_values4_mut(WrappingPos(0, 0)) {
+            *value += 1;
+        }
+        assert_eq!(narrow[(0, 0)], 1);
+        assert_eq!(narrow[(1, 0)], 1);
+    }
+
+    #[test]
+    fn grid_parsing() {
+        use util::Grid;
+
+        let grid = Grid::parse_digits("  123\n  456\n").unwrap();
+        assert_eq!(grid.dim(), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+
+        let grid = Grid::parse_chars("#.\n.#").unwrap();
+        assert_eq!(&*grid, ['#', '.', '.', '#']);
+
+        let grid = Grid::parse_with("ab\nba", |b| Ok::<_, anyhow::Error>(b == b'a')).unwrap();
+        assert_eq!(&*grid, [true, false, false, true]);
+        assert_eq!(grid.map(|&a| u8::from(a)).to_string(), "10\n01");
+
+        // only digit grids are trimmed, whitespace is a cell otherwise
+        let grid = Grid::parse_chars(" #\n# ").unwrap();
+        assert_eq!(&*grid, [' ', '#', '#', ' ']);
+
+        let err = Grid::parse_digits("123\n456\n78\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: expected 3 cells, found 2");
+
+        let err = Grid::parse_digits("123\n4x6").unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "line 2: unable to parse 'x': 'x' is not a digit"
+        );
+
+        assert!(Grid::parse_chars("\u{e9}").is_err());
+        assert!(Grid::parse_digits("").is_err());
+        assert!(Grid::parse_digits("\n\n").is_err());
+    }
+
+    #[test]
+    fn grid_rendering() {
+        use util::{Grid, WrappingPos};
+
+        let heights = Grid::parse_digits("123\n456\n789").unwrap();
+        assert_eq!(heights.to_string(), "123\n456\n789");
+        assert_eq!(Grid::parse_digits(&heights.to_string()).unwrap(), heights);
+
+        let map = Grid::parse_chars("#..\n.#.").unwrap();
+        assert_eq!(Grid::parse_chars(&map.to_string()).unwrap(), map);
+
+        let path = heights
+            .render_with(|_| '.')
+            .highlight([(0, 0), (1, 0), (1, 1), (5, 5)], '*')
+            .highlight([WrappingPos(4, 4)], 'o');
+        assert_eq!(path.to_string(), "**.\n.o.\n...");
+
+        // the highlighted rendering is a grid itself
+        let path = Grid::parse_chars(&path.to_string()).unwrap();
+        assert_eq!(path.dim(), heights.dim());
+        assert_eq!(path[(1, 1)], 'o');
+
+        let tall: Grid<u8> = (vec![9, 10], 2).try_into().unwrap();
+        assert_eq!(tall.to_string(), "9+");
+        assert!(Grid::parse_digits(&tall.to_string()).is_err());
+    }
+
+    #[test]
+    fn grid_round_trip() {
+        use util::Grid;
+
+        // whitespace at the edges of the rows is kept
+        let map: Grid<char> = (" #. \t..#  .#".chars().collect(), 4).try_into().unwrap();
+        assert_eq!(map.dim(), (4, 3));
+        assert_eq!(Grid::parse_chars(&map.to_string()).unwrap(), map);
+
+        // values above 9 round-trip with a wider alphabet
+        let energies: Grid<u8> = (vec![0, 9, 10, 35, 17, 1], 3).try_into().unwrap();
+        let text = energies
+            .render_with(|&v| char::from_digit(u32::from(v), 36).unwrap())
+            .to_string();
+        assert_eq!(text, "09a\nzh1");
+        let parsed = Grid::parse_with(&text, |b| {
+            (b as char)
+                .to_digit(36)
+                .map(|v| v as u8)
+                .ok_or_else(|| anyhow::anyhow!("not a base 36 digit"))
+        })
+        .unwrap();
+        assert_eq!(parsed, energies);
+    }
+
+    #[test]
+    fn grid_rows_and_cols() {
+        use util::{Grid, WrappingPos};
+
+        let mut grid: Grid<u8> = ((0..6).collect::<Vec<_>>(), 3).try_into().unwrap();
+
+        assert_eq!(grid.row(1), [3, 4, 5]);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(
+            grid.cols()
+                .map(|col| col.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[0, 3], [1, 4], [2, 5]]
+        );
+        assert_eq!(grid.row_iter_mut(1).count(), 3);
+
+        for v in grid.row_iter_mut(1) {
+            *v += 10;
+        }
+        assert_eq!(grid.row(0), [0, 1, 2]);
+        assert_eq!(grid.row(1), [13, 14, 15]);
+
+        grid.row_mut(0).reverse();
+        grid.swap_cells((0, 0), (2, 1));
+        assert_eq!(&*grid, [15, 1, 0, 13, 14, 2]);
+
+        // the slice method still swaps by offset into the values
+        grid.swap(0, 5);
+        assert_eq!(&*grid, [2, 1, 0, 13, 14, 15]);
+        grid.swap_cells(WrappingPos(3, 0), WrappingPos(1, 1));
+        assert_eq!(&*grid, [14, 1, 0, 13, 2, 15]);
+
+        for row in grid.rows_mut() {
+            row[0] = 7;
+        }
+        assert_eq!(grid.col_iter(0).copied().collect::<Vec<_>>(), [7, 7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_row_outside() {
+        let grid: util::Grid<u8> = (vec![0; 6], 3).try_into().unwrap();
+        grid.row(2);
+    }
+
+    #[test]
+    fn grid_sub_grids() {
+        use util::Grid;
+
+        let mut grid = Grid::parse_digits("0123\n4567\n8901").unwrap();
+
+        let sub = grid.sub_grid((1, 1), (2, 2)).unwrap();
+        assert_eq!(sub.dim(), (2, 2));
+        assert_eq!(sub[(0, 0)], 5);
+        assert_eq!(sub[(1, 1)], 0);
+        assert_eq!(sub.get((2, 0)), None);
+        assert!(!sub.contains((0, 2)));
+        assert_eq!(sub.rows().collect::<Vec<_>>(), [[5, 6], [9, 0]]);
+        assert_eq!(sub.index_iter().map(|pos| sub[pos]).sum::<u8>(), 20);
+
+        assert!(grid.sub_grid((3, 0), (2, 1)).is_none());
+        assert!(grid.sub_grid((0, 0), (4, 3)).is_some());
+        assert!(grid.sub_grid((usize::MAX, 0), (2, 1)).is_none());
+
+        let mut sub = grid.sub_grid_mut((2, 0), (2, 3)).unwrap();
+        sub[(0, 0)] = 9;
+        sub.row_mut(2).fill(0);
+        *sub.get_mut((1, 1)).unwrap() = 5;
+        assert!(sub.get_mut((2, 0)).is_none());
+        assert_eq!(sub.as_sub_grid().row(1), [6, 5]);
+        assert_eq!(grid.to_string(), "0193\n4565\n8900");
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_grid_index_outside() {
+        let grid = util::Grid::parse_digits("0123\n4567").unwrap();
+        let sub = grid.sub_grid((0, 0), (2, 2)).unwrap();
+        // (2, 0) lies on the grid, but not on the sub-grid
+        let _ = sub[(2, 0)];
+    }
+
+    #[test]
+    fn collected_reports_line() {
+        assert_eq!(util::collected::<u8>("1\n 2\n3").unwrap(), [1, 2, 3]);
+
+        let err = util::collected::<u8>("1\n2\nx\n4").unwrap_err();
+        assert!(err.to_string().starts_with("line 3:"));
+    }
+
     #[test]
     fn base16_ok() {
         assert_eq!(util::base16_decode("AB").unwrap(), &[0xABu8]);
@@ -382,6 +1426,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::redundant_pattern_matching)]
     fn base16_err() {
         assert!(matches!(util::base16_decode("000"), Err(_)));
         assert!(matches!(util::base16_decode("GG"), Err(_)));
@@ -5,44 +5,34 @@
 
 use app::{app, help};
 
+mod alloc;
 mod app;
-mod quiz01;
-mod quiz02;
-mod quiz03;
-mod quiz04;
-mod quiz06;
-mod quiz07;
-mod quiz08;
-mod quiz09;
-mod quiz10;
-mod quiz11;
-mod quiz13;
-mod quiz14;
-mod quiz15;
-mod quiz16;
+mod bench;
+mod check;
+mod client;
+mod fetch;
+mod history;
+mod input;
+mod isolate;
+mod pool;
+mod render;
+mod report;
+mod scaffold;
+mod submit;
+
+/// The global allocator, counting allocations for `--alloc-stats`
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
 
 /// The applications main entry point
 fn main() {
-    let quizzes = all_the_quizzes![
-        quiz01: "inputs/1",
-        quiz02: "inputs/2",
-        quiz03: "inputs/3",
-        quiz04: "inputs/4",
-        quiz06: "inputs/6",
-        quiz07: "inputs/7",
-        quiz08: "inputs/8",
-        quiz09: "inputs/9",
-        quiz10: "inputs/10",
-        quiz11: "inputs/11",
-        quiz13: "inputs/13",
-        quiz14: "inputs/14",
-        quiz15: "inputs/15",
-        quiz16: "inputs/16",
-    ];
-
-    if let Err(e) = app(&quizzes) {
-        eprintln!("{}", e);
-        help(std::io::stderr());
-        std::process::exit(1);
+    match app(aoc21::days::all()) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            help(std::io::stderr());
+            std::process::exit(1);
+        }
     }
 }
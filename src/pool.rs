@@ -0,0 +1,116 @@
+//! A minimal thread pool which hands out results in order
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Process all tasks on up to `jobs` threads
+///
+/// The results are passed to `collect` on the calling thread in the order of the tasks, as soon as
+/// all preceding results are available.
+///
+/// # Errors
+/// Stops at the first error returned by `collect`. Tasks which are already running are finished,
+/// but their results are discarded.
+pub fn ordered<T, R, W, C>(jobs: usize, tasks: &[T], work: W, mut collect: C) -> anyhow::Result<()>
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    C: FnMut(&T, R) -> anyhow::Result<()>,
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let tx = tx.clone();
+            let next = &next;
+            let work = &work;
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+
+                let task = match tasks.get(index) {
+                    Some(task) => task,
+                    None => break,
+                };
+
+                if tx.send((index, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+
+        for (index, result) in rx {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&expected) {
+                collect(&tasks[expected], result)?;
+                expected += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_in_order() {
+        let tasks: Vec<u64> = (0..32).collect();
+        let mut results = Vec::new();
+
+        ordered(
+            4,
+            &tasks,
+            |&task| {
+                // finish later tasks first
+                thread::sleep(Duration::from_millis(32 - task));
+                task * 2
+            },
+            |&task, result| {
+                results.push((task, result));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            results,
+            tasks.iter().map(|&t| (t, t * 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn stops_on_error() {
+        let tasks: Vec<usize> = (0..8).collect();
+        let mut collected = 0;
+
+        let result = ordered(
+            2,
+            &tasks,
+            |&task| task,
+            |_, result| {
+                collected += 1;
+                if result == 3 {
+                    Err(anyhow::anyhow!("stop"))
+                } else {
+                    Ok(())
+                }
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(collected, 4);
+    }
+}
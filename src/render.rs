@@ -0,0 +1,169 @@
+//! Rendering of the frames of visualized quizzes
+use anyhow::Context;
+use aoc21::visualize::Frame;
+use std::fs::{self, File};
+use std::io::{self, stdout, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// How long each frame of a terminal animation is shown
+const FRAME_DELAY: Duration = Duration::from_millis(50);
+
+/// Where the frames of a visualization go
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    /// An animation drawn in the terminal with ANSI escape sequences
+    Terminal,
+    /// A numbered series of PGM images in a directory
+    Images(PathBuf),
+}
+
+impl Target {
+    /// The target of `--visualize`, the terminal unless a directory is given
+    pub fn new(dir: Option<String>) -> Self {
+        match dir {
+            Some(dir) => Self::Images(PathBuf::from(dir)),
+            None => Self::Terminal,
+        }
+    }
+}
+
+/// Renders the frames of a single part of a quiz to a [`Target`]
+pub struct Sink<'a> {
+    /// Where the frames go
+    target: &'a Target,
+    /// The day of the quiz
+    day: usize,
+    /// The part of the quiz
+    part: usize,
+    /// The number of frames rendered so far
+    frames: usize,
+}
+
+impl<'a> Sink<'a> {
+    /// Prepare the target for the frames of a part, clearing the terminal or creating the image directory
+    pub fn new(target: &'a Target, day: usize, part: usize) -> anyhow::Result<Self> {
+        match target {
+            Target::Terminal => print!("\x1b[2J"),
+            Target::Images(dir) => fs::create_dir_all(dir)
+                .with_context(|| format!("creating image directory {} failed", dir.display()))?,
+        }
+
+        Ok(Self {
+            target,
+            day,
+            part,
+            frames: 0,
+        })
+    }
+
+    /// Render the next frame
+    pub fn frame(&mut self, frame: &Frame) -> anyhow::Result<()> {
+        self.frames += 1;
+
+        match self.target {
+            Target::Terminal => {
+                let mut out = stdout().lock();
+
+                write!(out, "\x1b[H")?;
+                ansi(&mut out, frame)?;
+                writeln!(
+                    out,
+                    "quiz{:0>2}-part{} frame {}",
+                    self.day, self.part, self.frames
+                )?;
+                out.flush()?;
+
+                thread::sleep(FRAME_DELAY);
+            }
+            Target::Images(dir) => {
+                let path = dir.join(format!(
+                    "quiz{:0>2}-part{}-{:0>5}.pgm",
+                    self.day, self.part, self.frames
+                ));
+                let mut file = File::create(&path)
+                    .map(BufWriter::new)
+                    .with_context(|| format!("creating {} failed", path.display()))?;
+
+                pgm(&mut file, frame)
+                    .and_then(|_| file.flush())
+                    .with_context(|| format!("writing {} failed", path.display()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The number of frames rendered
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+/// Draw a frame with ANSI escape sequences, two pixels per character
+///
+/// Each character is an upper half block, the upper pixel is its foreground and the lower one its background.
+fn ansi<W: Write>(out: &mut W, frame: &Frame) -> io::Result<()> {
+    let (width, height) = frame.dim();
+
+    for y in (0..height).step_by(2) {
+        for x in 0..width {
+            let upper = frame.get((x, y)).unwrap_or_default();
+            let lower = frame.get((x, y + 1)).unwrap_or_default();
+
+            write!(
+                out,
+                "\x1b[38;5;{}m\x1b[48;5;{}m\u{2580}",
+                gray(upper),
+                gray(lower)
+            )?;
+        }
+
+        writeln!(out, "\x1b[0m")?;
+    }
+
+    Ok(())
+}
+
+/// The color of the 256 color palette closest to a shade of gray
+fn gray(shade: u8) -> u8 {
+    match shade {
+        0 => 16,
+        255 => 231,
+        // the grayscale ramp runs from 232 (almost black) to 255 (almost white)
+        shade => 232 + shade / 11,
+    }
+}
+
+/// Write a frame as a binary PGM image
+fn pgm<W: Write>(out: &mut W, frame: &Frame) -> io::Result<()> {
+    let (width, height) = frame.dim();
+
+    write!(out, "P5\n{} {}\n255\n", width, height)?;
+    out.write_all(frame.pixels())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendering() {
+        let mut frame = Frame::new(2, 3);
+        frame.set((0, 0), 255);
+        frame.set((1, 1), 128);
+
+        let mut image = Vec::new();
+        pgm(&mut image, &frame).unwrap();
+        assert_eq!(image, b"P5\n2 3\n255\n\xff\x00\x00\x80\x00\x00");
+
+        let mut drawing = Vec::new();
+        ansi(&mut drawing, &frame).unwrap();
+        assert_eq!(
+            String::from_utf8(drawing).unwrap(),
+            "\x1b[38;5;231m\x1b[48;5;16m\u{2580}\x1b[38;5;16m\x1b[48;5;243m\u{2580}\x1b[0m\n\
+             \x1b[38;5;16m\x1b[48;5;16m\u{2580}\x1b[38;5;16m\x1b[48;5;16m\u{2580}\x1b[0m\n"
+        );
+    }
+}
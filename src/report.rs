@@ -0,0 +1,631 @@
+//! Reporting of quiz results in human- or machine-readable formats
+use crate::alloc::AllocStats;
+use crate::check::{Answers, Verdict};
+use crate::isolate::Failure;
+use aoc21::{Answer, AnyParsed};
+use serde::Serialize;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The output format of a run
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Human-readable text lines
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Comma separated values with a header line
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(anyhow::anyhow!("unknown format '{}'", s)),
+        }
+    }
+}
+
+/// The outcome of parsing the input of a quiz
+pub struct ParseResult {
+    /// The day of the quiz
+    pub day: usize,
+    /// The parsed input, or why it could not be parsed
+    pub parsed: anyhow::Result<Arc<AnyParsed>>,
+    /// The execution time
+    pub elapsed: Duration,
+    /// The allocations, if they were counted
+    pub alloc: Option<AllocStats>,
+}
+
+/// The outcome of running a single part of a quiz
+pub struct PartResult {
+    /// The day of the quiz
+    pub day: usize,
+    /// The part of the quiz
+    pub part: usize,
+    /// The answer, or why none could be found
+    pub answer: anyhow::Result<Answer>,
+    /// The execution time
+    pub elapsed: Duration,
+    /// The allocations, if they were counted
+    pub alloc: Option<AllocStats>,
+}
+
+impl PartResult {
+    /// Check the answer against the expected answers
+    ///
+    /// A part which failed to produce an answer fails, unless no answer is expected.
+    pub fn verify(&self, answers: &Answers) -> Verdict {
+        match &self.answer {
+            Ok(answer) => answers.verify(self.day, self.part, answer),
+            Err(_) if answers.expected(self.day, self.part).is_some() => Verdict::Error,
+            Err(_) => Verdict::Missing,
+        }
+    }
+
+    /// Check the answer against the expected answer of an example
+    ///
+    /// A part which failed to produce an answer always fails.
+    pub fn verify_example(&self, expected: &str) -> Verdict {
+        match &self.answer {
+            Ok(answer) => Verdict::new(expected, answer),
+            Err(_) => Verdict::Error,
+        }
+    }
+}
+
+/// The status of a part as reported in machine-readable formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The part produced an answer which was not verified
+    Ok,
+    /// The part failed to produce an answer
+    Error,
+    /// The part did not finish within the timeout
+    Timeout,
+    /// The part panicked
+    Panic,
+    /// The part was not run, e.g. because there is no input
+    Skipped,
+    /// The answer matches the expected answer
+    Pass,
+    /// The answer does not match the expected answer
+    Fail,
+    /// There is no expected answer
+    Missing,
+}
+
+impl Status {
+    /// The status of a part, taking its verdict into account if it was verified
+    fn new(result: &PartResult, verdict: Option<&Verdict>) -> Self {
+        match (&result.answer, verdict) {
+            (Err(e), _) => Status::failed(e),
+            (Ok(_), None) => Status::Ok,
+            (Ok(_), Some(Verdict::Pass)) => Status::Pass,
+            (Ok(_), Some(Verdict::Fail(_))) => Status::Fail,
+            (Ok(_), Some(Verdict::Missing)) => Status::Missing,
+            (Ok(_), Some(Verdict::Error)) => Status::Error,
+        }
+    }
+
+    /// The status of a step which failed with the given error
+    fn failed(error: &anyhow::Error) -> Self {
+        match error.downcast_ref() {
+            Some(Failure::Timeout(_)) => Status::Timeout,
+            Some(Failure::Panic(_)) => Status::Panic,
+            None => Status::Error,
+        }
+    }
+
+    /// The status as written to CSV
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+            Status::Panic => "panic",
+            Status::Skipped => "skipped",
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+/// A single record of machine-readable output
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Record<'a> {
+    /// The parsing of the input of a quiz
+    Parse {
+        /// The day of the quiz
+        day: usize,
+        /// The execution time in nanoseconds
+        duration_ns: u64,
+        /// The allocations, if they were counted
+        #[serde(flatten)]
+        alloc: Option<AllocStats>,
+        /// Whether parsing succeeded (`ok`) or failed (`error`, `timeout` or `panic`)
+        status: Status,
+    },
+    /// The result of a single part of a quiz
+    Part {
+        /// The day of the quiz
+        day: usize,
+        /// The part of the quiz
+        part: usize,
+        /// The answer, empty if there is none
+        answer: &'a str,
+        /// The kind of the answer (`int`, `text` or `bitmap`), if there is one
+        kind: Option<&'static str>,
+        /// The execution time in nanoseconds
+        duration_ns: u64,
+        /// The allocations, if they were counted
+        #[serde(flatten)]
+        alloc: Option<AllocStats>,
+        /// The status of the part
+        status: Status,
+    },
+    /// The summary of the whole run
+    Summary {
+        /// The summed execution time of all parts in nanoseconds
+        duration_ns: u64,
+        /// The wall time of the whole run in nanoseconds
+        wall_ns: u64,
+        /// Whether the run passed (`ok`) or failed (`fail`) verification
+        status: Status,
+    },
+}
+
+impl Record<'_> {
+    /// The header line of CSV output
+    const CSV_HEADER: &'static str =
+        "type,day,part,answer,kind,duration_ns,wall_ns,allocations,alloc_bytes,peak_bytes,status";
+
+    /// Write the record as CSV line
+    fn write_csv<W: Write>(&self, mut w: W) -> std::io::Result<()> {
+        match self {
+            Record::Parse {
+                day,
+                duration_ns,
+                alloc,
+                status,
+            } => writeln!(
+                w,
+                "parse,{},,,,{},,{},{}",
+                day,
+                duration_ns,
+                csv_alloc(alloc),
+                status.as_str()
+            ),
+            Record::Part {
+                day,
+                part,
+                answer,
+                kind,
+                duration_ns,
+                alloc,
+                status,
+            } => writeln!(
+                w,
+                "part,{},{},{},{},{},,{},{}",
+                day,
+                part,
+                csv_field(answer),
+                kind.unwrap_or_default(),
+                duration_ns,
+                csv_alloc(alloc),
+                status.as_str()
+            ),
+            Record::Summary {
+                duration_ns,
+                wall_ns,
+                status,
+            } => writeln!(
+                w,
+                "summary,,,,,{},{},,,,{}",
+                duration_ns,
+                wall_ns,
+                status.as_str()
+            ),
+        }
+    }
+}
+
+/// Quote a CSV field if necessary
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The allocation fields of a CSV line, empty if the allocations were not counted
+fn csv_alloc(alloc: &Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!("{},{},{}", alloc.allocations, alloc.bytes, alloc.peak),
+        None => String::from(",,"),
+    }
+}
+
+/// Format allocation statistics for text output
+fn text_alloc(alloc: &AllocStats) -> String {
+    format!(
+        " {:>9} allocs {:>12} B {:>12} B peak",
+        alloc.allocations, alloc.bytes, alloc.peak
+    )
+}
+
+/// Convert a duration to nanoseconds, saturating at [`u64::MAX`]
+pub fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Writes the results of a run in the requested format
+pub struct Reporter<W: Write> {
+    /// The output format
+    format: Format,
+    /// Where the output is written to
+    out: W,
+}
+
+impl<W: Write> Reporter<W> {
+    /// Create a new reporter, writing the header of the format if it has one
+    pub fn new(format: Format, mut out: W) -> anyhow::Result<Self> {
+        if format == Format::Csv {
+            writeln!(out, "{}", Record::CSV_HEADER)?;
+        }
+
+        Ok(Self { format, out })
+    }
+
+    /// Write a single record in a machine-readable format
+    fn record(&mut self, record: Record) -> anyhow::Result<()> {
+        match self.format {
+            Format::Text => {}
+            Format::Json => {
+                serde_json::to_writer(&mut self.out, &record)?;
+                writeln!(self.out)?;
+            }
+            Format::Csv => record.write_csv(&mut self.out)?,
+        }
+
+        Ok(())
+    }
+
+    /// Start reporting the results of a quiz
+    pub fn quiz(&mut self, day: usize, title: &str) -> anyhow::Result<()> {
+        if self.format == Format::Text {
+            writeln!(self.out, "Day {}: {}", day, title)?;
+        }
+
+        Ok(())
+    }
+
+    /// Report how long parsing the input took
+    ///
+    /// If parsing failed, the error is printed to stderr.
+    pub fn parse(&mut self, result: &ParseResult) -> anyhow::Result<()> {
+        if let Err(e) = &result.parsed {
+            eprintln!("{:#}", e);
+        }
+
+        if self.format != Format::Text {
+            return self.record(Record::Parse {
+                day: result.day,
+                duration_ns: nanos(result.elapsed),
+                alloc: result.alloc,
+                status: match &result.parsed {
+                    Ok(_) => Status::Ok,
+                    Err(e) => Status::failed(e),
+                },
+            });
+        }
+
+        write!(
+            self.out,
+            "quiz{:0>2}-parse {:>9} us{}",
+            result.day,
+            result.elapsed.as_micros(),
+            result.alloc.as_ref().map(text_alloc).unwrap_or_default()
+        )?;
+
+        match &result.parsed {
+            Ok(_) => writeln!(self.out)?,
+            Err(e) => writeln!(
+                self.out,
+                " {:>16}",
+                Status::failed(e).as_str().to_uppercase()
+            )?,
+        }
+
+        Ok(())
+    }
+
+    /// Report the result of a part, optionally together with its verdict
+    ///
+    /// If the part failed, the error is printed to stderr. In the text format, answers spanning multiple lines
+    /// like bitmaps are drawn below the line of the part.
+    pub fn part(&mut self, result: &PartResult, verdict: Option<&Verdict>) -> anyhow::Result<()> {
+        let answer = match &result.answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => {
+                eprintln!("{:#}", e);
+                String::new()
+            }
+        };
+
+        if self.format != Format::Text {
+            return self.record(Record::Part {
+                day: result.day,
+                part: result.part,
+                answer: &answer,
+                kind: result.answer.as_ref().ok().map(|a| a.kind().as_str()),
+                duration_ns: nanos(result.elapsed),
+                alloc: result.alloc,
+                status: Status::new(result, verdict),
+            });
+        }
+
+        let (column, below) = match &result.answer {
+            Ok(a) if a.is_multiline() => (String::new(), Some(answer)),
+            Ok(_) => (answer, None),
+            Err(e) => (Status::failed(e).as_str().to_uppercase(), None),
+        };
+
+        write!(
+            self.out,
+            "quiz{:0>2}-part{} {:>9} us{} {:>16}",
+            result.day,
+            result.part,
+            result.elapsed.as_micros(),
+            result.alloc.as_ref().map(text_alloc).unwrap_or_default(),
+            column
+        )?;
+
+        match verdict {
+            Some(verdict) => writeln!(self.out, " {}", verdict)?,
+            None => writeln!(self.out)?,
+        }
+
+        for line in below.iter().flat_map(|below| below.lines()) {
+            writeln!(self.out, "    {}", line)?;
+        }
+
+        Ok(())
+    }
+
+    /// Highlight that the answer of a part changed since the previous run
+    ///
+    /// Only the text format highlights changes, the records of the machine-readable formats stay as they are.
+    pub fn changed(&mut self, day: usize, part: usize, previous: &Answer) -> anyhow::Result<()> {
+        if self.format != Format::Text {
+            return Ok(());
+        }
+
+        if previous.is_multiline() {
+            writeln!(self.out, "quiz{:0>2}-part{} CHANGED, was", day, part)?;
+
+            for line in previous.to_string().lines() {
+                writeln!(self.out, "    {}", line)?;
+            }
+        } else {
+            writeln!(
+                self.out,
+                "quiz{:0>2}-part{} CHANGED, was {}",
+                day, part, previous
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Report that the given parts of a quiz were not run
+    pub fn skipped(
+        &mut self,
+        day: usize,
+        parts: &[usize],
+        error: &anyhow::Error,
+    ) -> anyhow::Result<()> {
+        eprintln!("quiz{:0>2} skipped: {:#}", day, error);
+
+        for &part in parts {
+            self.record(Record::Part {
+                day,
+                part,
+                answer: "",
+                kind: None,
+                duration_ns: 0,
+                alloc: None,
+                status: Status::Skipped,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Finish reporting the results of a quiz
+    pub fn end_quiz(&mut self) -> anyhow::Result<()> {
+        if self.format == Format::Text {
+            writeln!(self.out)?;
+        }
+
+        Ok(())
+    }
+
+    /// Report the summed execution time, the wall time and whether all verified parts passed
+    pub fn summary(&mut self, total: Duration, wall: Duration, passed: bool) -> anyhow::Result<()> {
+        if self.format != Format::Text {
+            let status = if passed { Status::Ok } else { Status::Fail };
+
+            return self.record(Record::Summary {
+                duration_ns: nanos(total),
+                wall_ns: nanos(wall),
+                status,
+            });
+        }
+
+        writeln!(
+            self.out,
+            "Total execution time: {:>7.2} ms (wall time: {:.2} ms)",
+            total.as_secs_f64() * 1000.,
+            wall.as_secs_f64() * 1000.
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Report a run of two parts in the given format
+    fn report(format: Format) -> String {
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(format, &mut out).unwrap();
+
+        reporter.quiz(13, "Transparent Origami").unwrap();
+        reporter
+            .parse(&ParseResult {
+                day: 13,
+                parsed: Ok(Arc::new(Box::new(()))),
+                elapsed: Duration::from_micros(5),
+                alloc: None,
+            })
+            .unwrap();
+        reporter
+            .part(
+                &PartResult {
+                    day: 13,
+                    part: 1,
+                    answer: Ok(Answer::Int(17)),
+                    elapsed: Duration::from_micros(12),
+                    alloc: Some(AllocStats {
+                        allocations: 3,
+                        bytes: 96,
+                        peak: 64,
+                    }),
+                },
+                None,
+            )
+            .unwrap();
+        reporter
+            .part(
+                &PartResult {
+                    day: 13,
+                    part: 2,
+                    answer: Ok(Answer::Text(String::from("#, \"#\"\n#"))),
+                    elapsed: Duration::from_micros(34),
+                    alloc: None,
+                },
+                Some(&Verdict::Pass),
+            )
+            .unwrap();
+        reporter.end_quiz().unwrap();
+        reporter
+            .summary(Duration::from_micros(46), Duration::from_micros(50), true)
+            .unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            report(Format::Json),
+            r##"{"type":"parse","day":13,"duration_ns":5000,"status":"ok"}
+{"type":"part","day":13,"part":1,"answer":"17","kind":"int","duration_ns":12000,"allocations":3,"alloc_bytes":96,"peak_bytes":64,"status":"ok"}
+{"type":"part","day":13,"part":2,"answer":"#, \"#\"\n#","kind":"text","duration_ns":34000,"status":"pass"}
+{"type":"summary","duration_ns":46000,"wall_ns":50000,"status":"ok"}
+"##
+        );
+    }
+
+    #[test]
+    fn failures() {
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(Format::Text, &mut out).unwrap();
+
+        for (part, failure) in [
+            (1, Failure::Timeout(Duration::from_secs(1))),
+            (2, Failure::Panic(String::from("oops"))),
+        ] {
+            reporter
+                .part(
+                    &PartResult {
+                        day: 11,
+                        part,
+                        answer: Err(anyhow::Error::from(failure).context("quiz11 failed")),
+                        elapsed: Duration::from_secs(1),
+                        alloc: None,
+                    },
+                    None,
+                )
+                .unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "quiz11-part1   1000000 us          TIMEOUT\nquiz11-part2   1000000 us            PANIC\n"
+        );
+    }
+
+    #[test]
+    fn failed_verification() {
+        let answers: Answers = "11 1 1656".parse().unwrap();
+        let failed = |part| PartResult {
+            day: 11,
+            part,
+            answer: Err(anyhow::anyhow!("quiz11 failed")),
+            elapsed: Duration::from_secs(1),
+            alloc: None,
+        };
+
+        assert_eq!(failed(1).verify(&answers), Verdict::Error);
+        assert_eq!(failed(1).verify(&answers).to_string(), "FAIL (no answer)");
+        assert!(failed(1).verify(&answers).is_failure());
+        assert_eq!(failed(2).verify(&answers), Verdict::Missing);
+        assert_eq!(failed(2).verify_example("195"), Verdict::Error);
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            report(Format::Text),
+            "Day 13: Transparent Origami
+quiz13-parse         5 us
+quiz13-part1        12 us         3 allocs           96 B           64 B peak               17
+quiz13-part2        34 us                  PASS
+    #, \"#\"
+    #
+
+Total execution time:    0.05 ms (wall time: 0.05 ms)
+"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            report(Format::Csv),
+            r##"type,day,part,answer,kind,duration_ns,wall_ns,allocations,alloc_bytes,peak_bytes,status
+parse,13,,,,5000,,,,,ok
+part,13,1,17,int,12000,,3,96,64,ok
+part,13,2,"#, ""#""
+#",text,34000,,,,,pass
+summary,,,,,46000,50000,,,,ok
+"##
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Generation of the module of a new quiz
+use crate::app::help;
+use anyhow::Context;
+use std::ffi::OsString;
+use std::fs;
+use std::io::stdout;
+use std::path::{Path, PathBuf};
+
+/// A description of the command line arguments of the `new` command
+#[derive(Debug)]
+struct Args {
+    /// Option to print the help output
+    help: bool,
+    /// Option to set the title of the quiz
+    title: String,
+    /// Option to set the source directory of the crate
+    src: PathBuf,
+    /// The day of the quiz
+    day: usize,
+}
+
+impl Args {
+    /// Generates [`Args`] with the help of the pico-args crate
+    fn try_from_vec(args: Vec<OsString>) -> anyhow::Result<Self> {
+        let mut args = pico_args::Arguments::from_vec(args);
+
+        let help = args.contains(["-h", "--help"]);
+        let title = args
+            .opt_value_from_str("--title")?
+            .unwrap_or_else(|| String::from("Untitled"));
+        let src = args
+            .opt_value_from_str("--src")?
+            .unwrap_or_else(|| PathBuf::from("src"));
+
+        let day = if help {
+            0
+        } else {
+            match args.free_from_str().context("new requires a DAY")? {
+                day @ 1..=25 => day,
+                day => return Err(anyhow::anyhow!("'{}' is not a day of the advent", day)),
+            }
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(anyhow::anyhow!("unexpected arguments {:?}", remaining));
+        }
+
+        Ok(Self {
+            help,
+            title,
+            src,
+            day,
+        })
+    }
+}
+
+/// Runs the `new` command with the arguments following it
+pub fn command(args: Vec<OsString>) -> anyhow::Result<bool> {
+    let args = Args::try_from_vec(args)?;
+
+    if args.help {
+        help(stdout());
+        return Ok(true);
+    }
+
+    for path in scaffold(&args.src, args.day, &args.title)? {
+        println!("Day {}: wrote {}", args.day, path.display());
+    }
+
+    Ok(true)
+}
+
+/// Generate the module and the input file of a quiz and register it in `days.rs`
+///
+/// Nothing is written if the quiz exists already. Returns the files written.
+fn scaffold(src: &Path, day: usize, title: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let days = src.join("days.rs");
+    let module = src.join("days").join(format!("quiz{:0>2}.rs", day));
+    let inputs = src.join("inputs");
+    let input = inputs.join(day.to_string());
+
+    if module.exists() {
+        return Err(anyhow::anyhow!(
+            "day {} exists already in {}",
+            day,
+            module.display()
+        ));
+    }
+
+    let registered = register(
+        &fs::read_to_string(&days).with_context(|| format!("reading {} failed", days.display()))?,
+        day,
+    )
+    .with_context(|| format!("registering day {} in {} failed", day, days.display()))?;
+
+    fs::write(&module, template(day, title))
+        .with_context(|| format!("writing {} failed", module.display()))?;
+    let mut written = vec![module];
+
+    if !input.exists() {
+        fs::create_dir_all(&inputs)
+            .with_context(|| format!("creating {} failed", inputs.display()))?;
+        fs::write(&input, "").with_context(|| format!("writing {} failed", input.display()))?;
+        written.push(input);
+    }
+
+    fs::write(&days, registered).with_context(|| format!("writing {} failed", days.display()))?;
+    written.push(days);
+
+    Ok(written)
+}
+
+/// The module of a new quiz
+fn template(day: usize, title: &str) -> String {
+    format!(
+        r#"//! Day {day}: {title}
+use crate::{{Answer, Example, Quizzer}};
+
+/// Todays quiz implementation
+pub struct Quiz;
+
+impl Quizzer for Quiz {{
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part1(&self, _lines: &Self::Parsed) -> anyhow::Result<Answer> {{
+        Ok(Answer::default())
+    }}
+
+    fn part2(&self, _lines: &Self::Parsed) -> anyhow::Result<Answer> {{
+        Ok(Answer::default())
+    }}
+
+    fn examples(&self) -> &'static [Example] {{
+        &[Example {{
+            input: EXAMPLE,
+            part1: None,
+            part2: None,
+        }}]
+    }}
+}}
+
+/// The worked example of the quiz description
+const EXAMPLE: &str = "";
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn part1_examples() {{
+        let lines = Quiz.parse(EXAMPLE).unwrap();
+        assert_eq!(Quiz.part1(&lines).unwrap(), Answer::default());
+    }}
+
+    #[test]
+    fn part2_examples() {{
+        let lines = Quiz.parse(EXAMPLE).unwrap();
+        assert_eq!(Quiz.part2(&lines).unwrap(), Answer::default());
+    }}
+}}
+"#
+    )
+}
+
+/// Add the `mod` line and the `days!` entry of a quiz to the content of `days.rs`
+///
+/// Both are inserted in order of the days, next to the ones of the other quizzes.
+fn register(days: &str, day: usize) -> anyhow::Result<String> {
+    let mut lines: Vec<String> = days.lines().map(String::from).collect();
+
+    let module = |line: &str| {
+        line.strip_prefix("pub mod quiz")?
+            .strip_suffix(';')?
+            .parse::<usize>()
+            .ok()
+    };
+    let entry = |line: &str| {
+        line.trim_start()
+            .strip_prefix("quiz")?
+            .split_once(':')?
+            .0
+            .parse::<usize>()
+            .ok()
+    };
+
+    if lines.iter().any(|line| module(line) == Some(day)) {
+        return Err(anyhow::anyhow!("day {} is registered already", day));
+    }
+
+    insert(&mut lines, day, module, |_| {
+        format!("pub mod quiz{:0>2};", day)
+    })
+    .context("found no 'pub mod quizNN;' lines")?;
+    insert(&mut lines, day, entry, |line| {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        format!("{}quiz{:0>2}: \"inputs/{}\",", indent, day, day)
+    })
+    .context("found no 'quizNN: \"inputs/N\",' entries")?;
+
+    let mut registered = lines.join("\n");
+    registered.push('\n');
+
+    Ok(registered)
+}
+
+/// Insert a line among the lines of the other days, in order of the days
+///
+/// `day_of` returns the day a line belongs to, if it is one of these lines, `line` creates the new line from the
+/// first of them. Returns [`None`] if there are no such lines.
+fn insert<D, L>(lines: &mut Vec<String>, day: usize, day_of: D, line: L) -> Option<()>
+where
+    D: Fn(&str) -> Option<usize>,
+    L: Fn(&str) -> String,
+{
+    let days: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line)?)))
+        .collect();
+
+    let &(first, _) = days.first()?;
+    let index = match days.iter().find(|&&(_, other)| other > day) {
+        Some(&(index, _)) => index,
+        None => days.last()?.0 + 1,
+    };
+
+    let new = line(&lines[first]);
+    lines.insert(index, new);
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = r#"use crate::DynQuizzer;
+
+pub mod quiz01;
+pub mod quiz03;
+
+pub fn all() -> &'static [Day] {
+    let days = days![
+        quiz01: "inputs/1",
+        quiz03: "inputs/3",
+    ];
+}
+"#;
+
+    #[test]
+    fn registration() {
+        assert_eq!(
+            register(DAYS, 2).unwrap(),
+            r#"use crate::DynQuizzer;
+
+pub mod quiz01;
+pub mod quiz02;
+pub mod quiz03;
+
+pub fn all() -> &'static [Day] {
+    let days = days![
+        quiz01: "inputs/1",
+        quiz02: "inputs/2",
+        quiz03: "inputs/3",
+    ];
+}
+"#
+        );
+
+        let registered = register(DAYS, 12).unwrap();
+        assert!(registered.contains("pub mod quiz03;\npub mod quiz12;\n\n"));
+        assert!(registered.contains("        quiz12: \"inputs/12\",\n    ];"));
+
+        assert!(register(DAYS, 3).is_err());
+        assert!(register("fn main() {}", 3).is_err());
+    }
+
+    #[test]
+    fn refuse_existing() {
+        let src = std::env::temp_dir().join(format!("aoc21-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&src).unwrap();
+        fs::create_dir_all(src.join("days")).unwrap();
+        fs::write(src.join("days.rs"), DAYS).unwrap();
+
+        let written = scaffold(&src, 2, "Dive!").unwrap();
+        assert_eq!(written.len(), 3);
+        assert!(fs::read_to_string(src.join("days/quiz02.rs"))
+            .unwrap()
+            .starts_with("//! Day 2: Dive!\n"));
+        assert_eq!(fs::read_to_string(src.join("inputs/2")).unwrap(), "");
+
+        let days = fs::read_to_string(src.join("days.rs")).unwrap();
+        assert!(scaffold(&src, 2, "Dive!").is_err());
+        fs::remove_file(src.join("days/quiz02.rs")).unwrap();
+        assert!(scaffold(&src, 2, "Dive!").is_err());
+        assert_eq!(fs::read_to_string(src.join("days.rs")).unwrap(), days);
+
+        fs::remove_dir_all(&src).unwrap();
+    }
+}
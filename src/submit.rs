@@ -0,0 +1,327 @@
+//! Submission of answers to the Advent of Code website
+use crate::app::help;
+use crate::client::{self, Client, Outcome};
+use crate::input::{self, Inputs};
+use anyhow::Context;
+use aoc21::answer::Kind;
+use aoc21::days::Day;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io::{self, stdout, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A description of the command line arguments of the `submit` command
+#[derive(Debug)]
+struct Args {
+    /// Option to print the help output
+    help: bool,
+    /// Option to read the input from a file
+    input: Option<PathBuf>,
+    /// Option to set the directory the inputs are read from
+    input_dir: PathBuf,
+    /// Option to set the file the attempts are logged in
+    log: PathBuf,
+    /// The options of the client
+    client: client::Options,
+    /// The day of the quiz
+    day: usize,
+    /// The part of the quiz
+    part: usize,
+}
+
+impl Args {
+    /// Generates [`Args`] with the help of the pico-args crate
+    fn try_from_vec(args: Vec<OsString>) -> anyhow::Result<Self> {
+        let mut args = pico_args::Arguments::from_vec(args);
+
+        let help = args.contains(["-h", "--help"]);
+        let input = args.opt_value_from_str(["-i", "--input"])?;
+        let input_dir = args
+            .opt_value_from_str(["-d", "--input-dir"])?
+            .unwrap_or_else(|| PathBuf::from("inputs"));
+        let log = args
+            .opt_value_from_str("--log")?
+            .unwrap_or_else(|| PathBuf::from("submissions.jsonl"));
+        let client = client::Options::from_args(&mut args)?;
+
+        let (day, part) = if help {
+            (0, 0)
+        } else {
+            (
+                args.free_from_str()
+                    .context("submit requires a DAY and a PART")?,
+                args.free_from_str()
+                    .context("submit requires a DAY and a PART")?,
+            )
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(anyhow::anyhow!("unexpected arguments {:?}", remaining));
+        }
+
+        if !help && !(1..=2).contains(&part) {
+            return Err(anyhow::anyhow!("there is no part {}, only 1 and 2", part));
+        }
+
+        Ok(Self {
+            help,
+            input,
+            input_dir,
+            log,
+            client,
+            day,
+            part,
+        })
+    }
+}
+
+/// Runs the `submit` command with the arguments following it
+///
+/// Returns whether the answer was correct.
+pub fn command(quizzes: &[Day], args: Vec<OsString>) -> anyhow::Result<bool> {
+    let args = Args::try_from_vec(args)?;
+
+    if args.help {
+        help(stdout());
+        return Ok(true);
+    }
+
+    let entry = quizzes
+        .iter()
+        .find(|quiz| quiz.day == args.day)
+        .ok_or_else(|| anyhow::anyhow!("no quiz for day {}", args.day))?;
+
+    let inputs = Inputs {
+        file: args.input,
+        dir: Some(args.input_dir.clone()),
+    };
+    let input = inputs.load(entry)?;
+    input::warn(entry, &input);
+    let parsed = entry.quiz.parse(&input)?;
+
+    let answer = match args.part {
+        1 => entry.quiz.part1(&parsed)?,
+        _ => entry.quiz.part2(&parsed)?,
+    };
+
+    if answer.is_empty() {
+        return Err(anyhow::anyhow!(
+            "part {} of day {} has no answer",
+            args.part,
+            args.day
+        ));
+    }
+
+    if answer.kind() == Kind::Bitmap {
+        return Err(anyhow::anyhow!(
+            "part {} of day {} is a bitmap, submit the letters it shows by hand:\n{}",
+            args.part,
+            args.day,
+            answer
+        ));
+    }
+
+    let answer = answer.to_string();
+
+    fs::create_dir_all(&args.input_dir).with_context(|| {
+        format!(
+            "creating input directory {} failed",
+            args.input_dir.display()
+        )
+    })?;
+    let client = args.client.client(args.input_dir.join(".last-request"))?;
+
+    println!("Day {} part {}: submitting {}", args.day, args.part, answer);
+    let (outcome, message) = submit(&client, &args.log, args.day, args.part, &answer)?;
+    println!("Day {} part {}: {}", args.day, args.part, outcome);
+    println!("{}", message);
+
+    Ok(outcome == Outcome::Correct)
+}
+
+/// Submit an answer unless it was submitted before and log the attempt
+fn submit(
+    client: &Client,
+    log: &Path,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> anyhow::Result<(Outcome, String)> {
+    let attempts = Log::load(log)?;
+
+    if let Some(solved) = attempts.solved(day, part) {
+        return Err(anyhow::anyhow!(
+            "part {} of day {} is solved already with {}",
+            part,
+            day,
+            solved.answer
+        ));
+    }
+
+    if let Some(previous) = attempts.previous(day, part, answer) {
+        return Err(anyhow::anyhow!(
+            "{} was submitted for part {} of day {} before, it is {}",
+            answer,
+            part,
+            day,
+            previous.outcome
+        ));
+    }
+
+    let (outcome, message) = client.submit(day, part, answer)?;
+    Log::append(log, &Attempt::new(day, part, answer, outcome))?;
+
+    Ok((outcome, message))
+}
+
+/// A single submission of an answer
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Attempt {
+    /// The day of the quiz
+    pub day: usize,
+    /// The part of the quiz
+    pub part: usize,
+    /// The answer submitted
+    pub answer: String,
+    /// The outcome of the submission
+    pub outcome: Outcome,
+    /// When the answer was submitted, in seconds since the unix epoch
+    pub timestamp: u64,
+}
+
+impl Attempt {
+    /// Create an attempt made just now
+    fn new(day: usize, part: usize, answer: &str, outcome: Outcome) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+        }
+    }
+}
+
+/// All past submissions, oldest first
+///
+/// The log file contains one JSON object per attempt and line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Log(Vec<Attempt>);
+
+impl Log {
+    /// Load the log from a file, which is empty if the file does not exist yet
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("reading submission log {} failed", path.display()))
+            }
+        };
+
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                serde_json::from_str(line).with_context(|| {
+                    format!(
+                        "parsing submission log {} failed: line {}",
+                        path.display(),
+                        n + 1
+                    )
+                })
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(Self)
+    }
+
+    /// Append an attempt to the log file
+    pub fn append(path: &Path, attempt: &Attempt) -> anyhow::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening submission log {} failed", path.display()))?;
+
+        let mut line = serde_json::to_string(attempt)?;
+        line.push('\n');
+
+        file.write_all(line.as_bytes())
+            .with_context(|| format!("writing submission log {} failed", path.display()))
+    }
+
+    /// The correct answer to a part, if it was submitted
+    pub fn solved(&self, day: usize, part: usize) -> Option<&Attempt> {
+        self.0
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.outcome == Outcome::Correct)
+    }
+
+    /// The latest attempt at a part with the same answer which the server checked
+    pub fn previous(&self, day: usize, part: usize, answer: &str) -> Option<&Attempt> {
+        self.0.iter().rev().find(|a| {
+            a.day == day && a.part == part && a.answer == answer && a.outcome.is_checked()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+    use std::time::Duration;
+
+    #[test]
+    fn submit_once() {
+        let dir = std::env::temp_dir().join(format!("aoc21-submit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("submissions.jsonl");
+
+        let (url, server) = stub::server(
+            "<html><main><article><p>That's not the right answer; \
+             your answer is too high.</p></article></main></html>",
+        );
+        let client = Client::new(
+            &url,
+            String::from("token"),
+            Duration::ZERO,
+            dir.join(".last-request"),
+        );
+
+        let (outcome, message) = submit(&client, &log, 1, 2, "1234").unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        assert_eq!(
+            message,
+            "That's not the right answer; your answer is too high."
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2021/day/1/answer HTTP/1.1\n"));
+        assert!(request.contains("\nCookie: session=token\n"));
+        assert!(request.ends_with("\n\nlevel=2&answer=1234"));
+
+        // the stub is gone, so this must not reach the server
+        let err = submit(&client, &log, 1, 2, "1234").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1234 was submitted for part 2 of day 1 before, it is too high"
+        );
+
+        let attempts = Log::load(&log).unwrap();
+        assert_eq!(attempts.0.len(), 1);
+        assert_eq!(
+            attempts.previous(1, 2, "1234").unwrap().outcome,
+            Outcome::TooHigh
+        );
+        assert_eq!(attempts.solved(1, 2), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
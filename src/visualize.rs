@@ -0,0 +1,94 @@
+//! Visualization of the simulations some quizzes run
+//!
+//! ```
+//! use aoc21::util::Grid;
+//! use aoc21::visualize::Frame;
+//!
+//! let grid = Grid::try_from((vec![0u8, 5, 9, 1], 2)).unwrap();
+//! let frame = Frame::from_grid(&grid, |&height| height * 25);
+//!
+//! assert_eq!(frame.dim(), (2, 2));
+//! assert_eq!(frame.pixels(), &[0, 125, 225, 25]);
+//! ```
+use crate::util::Grid;
+use crate::Quizzer;
+
+/// A single grayscale image of a simulation, `0` is black and `255` white
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Frame {
+    /// The shades of the pixels, row by row
+    pixels: Vec<u8>,
+    /// The width of the frame
+    width: usize,
+    /// The height of the frame
+    height: usize,
+}
+
+impl Frame {
+    /// A black frame of the given dimensions
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixels: vec![0; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A frame with one pixel per cell of a grid, shaded by the given function
+    pub fn from_grid<T, F>(grid: &Grid<T>, shade: F) -> Self
+    where
+        F: Fn(&T) -> u8,
+    {
+        let (width, height) = grid.dim();
+
+        Self {
+            pixels: grid.iter().map(shade).collect(),
+            width,
+            height,
+        }
+    }
+
+    /// The dimensions of the frame
+    ///
+    /// Returns (width, height) of the frame
+    pub fn dim(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// The shades of the pixels, row by row
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The shade of the pixel at (x, y), if it lies within the frame
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<u8> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Shade the pixel at (x, y), pixels outside of the frame are ignored
+    pub fn set(&mut self, (x, y): (usize, usize), shade: u8) {
+        if x < self.width && y < self.height {
+            self.pixels[x + y * self.width] = shade;
+        }
+    }
+}
+
+/// Trait to be implemented by quizzes whose simulation can be watched frame by frame
+///
+/// A quiz makes its implementation known through [`Quizzer::visualizer`].
+pub trait Visualize: Quizzer {
+    /// Run a part of the quiz, passing a frame of each step of the simulation to `frame`
+    ///
+    /// # Errors
+    /// Returns an error if the quiz can not be solved for the input, or the first error `frame` returns.
+    fn visualize(
+        &self,
+        parsed: &Self::Parsed,
+        part: usize,
+        frame: &mut dyn FnMut(Frame) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()>;
+}